@@ -31,15 +31,16 @@ impl<'a, L: LineMarker + 'a> Checker<'a, L> {
 
     pub(crate) fn run_checks(&mut self) {
         if let Some(rule_config) = &self.config.cloudformation {
-            if rule_config.enabled(RuleType::LAMBDA_003, self.environment) {
+            let rule_context = rule_config.context(self.environment);
+            if rule_context.enabled(RuleType::LAMBDA_003) {
                 aws::lambda::check_lambda_missing_tag(
                     self.infra_template,
-                    rule_config,
+                    &rule_context,
                     self.error_reporter,
                     self.line_marker,
                 );
             }
-            if rule_config.enabled(RuleType::LAMBDA_002, self.environment) {
+            if rule_context.enabled(RuleType::LAMBDA_002) {
                 aws::lambda::check_lambda_architecture_arm(
                     self.infra_template,
                     self.error_reporter,
@@ -47,7 +48,7 @@ impl<'a, L: LineMarker + 'a> Checker<'a, L> {
                 );
             }
 
-            if rule_config.enabled(RuleType::LAMBDA_001, self.environment) {
+            if rule_context.enabled(RuleType::LAMBDA_001) {
                 aws::lambda::check_lambda_missing_log_group(
                     self.infra_template,
                     self.error_reporter,
@@ -55,350 +56,2311 @@ impl<'a, L: LineMarker + 'a> Checker<'a, L> {
                 );
             }
 
-            if rule_config.enabled(RuleType::LAMBDA_004, self.environment) {
+            if rule_context.enabled(RuleType::LAMBDA_004) {
                 aws::lambda::check_lambda_maxmimum_retry_attempts(
                     self.infra_template,
-                    rule_config,
+                    &rule_context,
                     self.error_reporter,
                     self.line_marker,
                 );
             }
 
-            if rule_config.enabled(RuleType::LAMBDA_005, self.environment)
-                || rule_config.enabled(RuleType::LAMBDA_006, self.environment)
-                || rule_config.enabled(RuleType::LAMBDA_007, self.environment)
+            if rule_context.enabled(RuleType::LAMBDA_005)
+                || rule_context.enabled(RuleType::LAMBDA_006)
+                || rule_context.enabled(RuleType::LAMBDA_007)
             {
                 aws::lambda::check_lambda_powertools_environment_variables(
                     self.infra_template,
-                    rule_config,
+                    &rule_context,
                     self.error_reporter,
                     self.line_marker,
-                    self.environment,
                 );
             }
 
-            if rule_config.enabled(RuleType::CW_001, self.environment)
-                || rule_config.enabled(RuleType::CW_002, self.environment)
-            {
+            if rule_context.enabled(RuleType::LAMBDA_008) {
+                aws::lambda::check_lambda_memory_size(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_009) {
+                aws::lambda::check_lambda_api_timeout(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_010) {
+                aws::lambda::check_lambda_ephemeral_storage(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_011) {
+                aws::lambda::check_lambda_provisioned_concurrency(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_012) {
+                aws::lambda::check_lambda_event_source_batch_size(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_013) {
+                aws::lambda::check_lambda_event_source_filter_criteria(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_014) {
+                aws::lambda::check_lambda_event_source_stream_retries(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::LAMBDA_015) {
+                aws::lambda::check_lambda_sqs_retry_amplification(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CW_001) || rule_context.enabled(RuleType::CW_002) {
                 aws::cloudwatch::check_cloudwatch_log_group_retention(
                     self.infra_template,
-                    rule_config,
+                    &rule_context,
                     self.error_reporter,
                     self.line_marker,
-                    self.environment,
                 );
             }
 
-            if rule_config.enabled(RuleType::CW_003, self.environment) {
+            if rule_context.enabled(RuleType::CW_003) {
                 aws::cloudwatch::check_cloudwatch_log_group_class(
                     self.infra_template,
                     self.error_reporter,
                     self.line_marker,
                 );
             }
-        }
-    }
-}
 
-#[cfg(test)]
-mod tests_cfn {
-    use crate::rules::violations::Violation;
+            if rule_context.enabled(RuleType::CW_004) {
+                aws::cloudwatch::check_cloudwatch_alarm_high_resolution(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
 
-    struct ExpectedViolation {
-        code: String,
-        resource: String,
-        message: String,
-    }
+            if rule_context.enabled(RuleType::CW_005) {
+                aws::cloudwatch::check_cloudwatch_alarms_per_function(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
 
-    impl ExpectedViolation {
-        fn new(violation: &dyn Violation, resource: &str) -> Self {
-            Self {
-                code: violation.code(),
-                resource: resource.to_string(),
-                message: violation.message(),
+            if rule_context.enabled(RuleType::CW_006) {
+                aws::cloudwatch::check_cloudwatch_metric_filter_log_group_class(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
             }
-        }
-    }
 
-    struct ExpectedViolations(Vec<ExpectedViolation>);
+            if rule_context.enabled(RuleType::CW_007) {
+                aws::cloudwatch::check_cloudwatch_subscription_filter_pattern(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
 
-    impl ExpectedViolations {
-        fn new(violations: Vec<ExpectedViolation>) -> Self {
-            Self(violations)
-        }
+            if rule_context.enabled(RuleType::CW_008) {
+                aws::cloudwatch::check_cloudwatch_dashboard_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
 
-        fn assert_all_match(&self, actual: &str) {
-            let actual_lines: Vec<&str> = actual
-                .lines()
-                .filter(|line| !line.is_empty() && !line.contains("src/fixtures/aws/"))
-                .collect();
-            for (i, expected) in self.0.iter().enumerate() {
-                let expected_str = format!(
-                    "{}:{}:{}",
-                    expected.code, expected.resource, expected.message
+            if rule_context.enabled(RuleType::ECS_001) {
+                aws::ecs::check_ecs_task_architecture_arm(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
                 );
-                let actual_line = actual_lines.get(i).unwrap_or(&"");
-                assert_eq!(
-                    actual_line, &expected_str,
-                    "\nExpected: {}\nActual: {}",
-                    expected_str, actual_line
+            }
+
+            if rule_context.enabled(RuleType::ECS_002) {
+                aws::ecs::check_ecs_service_fargate_spot(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
                 );
             }
-        }
-    }
 
-    mod cfn_tests {
-        use super::{ExpectedViolation, ExpectedViolations};
-        use crate::checker::Checker;
-        use crate::error_reporter::ErrorReporter;
-        use crate::parsers::cfn::{parse_cloudformation, CloudFormation};
-        use crate::parsers::config::{
-            Config, RuleConfig, RuleType, RuleTypeConfigDetail, ThresholdValue,
-        };
-        use crate::parsers::get_yaml_line_marker;
-        use crate::parsers::iac::InfratructureTemplate;
-        use crate::parsers::YamlLineMarker;
-        use crate::rules::violations::{CloudWatchViolation, LambdaViolation};
-        use rstest::*;
+            if rule_context.enabled(RuleType::ECS_003) {
+                aws::ecs::check_ecs_task_size(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
 
-        #[fixture]
-        fn test_config() -> Config {
-            let mut default_rule_config = RuleConfig::default();
-            for rule in default_rule_config.rules.values_mut() {
-                rule.enabled = false;
+            if rule_context.enabled(RuleType::ECS_004) {
+                aws::ecs::check_ecs_container_insights(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
             }
-            default_rule_config.environments.insert(
-                "default".to_string(),
-                Some(default_rule_config.rules.clone()),
-            );
 
-            Config {
-                cloudformation: Some(default_rule_config),
+            if rule_context.enabled(RuleType::ECS_005) {
+                aws::ecs::check_ecs_awslogs_log_group_retention(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
             }
-        }
 
-        fn get_error_reporter(path: &str) -> ErrorReporter {
-            ErrorReporter::new(&format!("src/fixtures/aws/{}", path))
-        }
+            if rule_context.enabled(RuleType::APIGW_001) {
+                aws::apigateway::check_apigateway_http_api_candidate(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
 
-        fn get_cloudformation(path: &str) -> CloudFormation {
-            let mut parsed_cfn =
-                parse_cloudformation(&format!("src/fixtures/aws/{}", path)).unwrap();
-            parsed_cfn.resolve_parameters(None, "default");
-            parsed_cfn
+            if rule_context.enabled(RuleType::APIGW_002) {
+                aws::apigateway::check_apigateway_cache_cluster_size(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::APIGW_003) {
+                aws::apigateway::check_apigateway_execution_logging(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::APIGW_004) {
+                aws::apigateway::check_apigateway_access_log_retention(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SFN_001) {
+                aws::stepfunctions::check_stepfunctions_express_workflow(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SFN_002) {
+                aws::stepfunctions::check_stepfunctions_logging_level(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SFN_003) {
+                aws::stepfunctions::check_stepfunctions_tracing(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::STREAM_001) {
+                aws::streams::check_stream_provisioned_shard_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::STREAM_002) {
+                aws::streams::check_stream_on_demand(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::STREAM_003) {
+                aws::streams::check_stream_retention_period(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::STREAM_004) {
+                aws::streams::check_stream_enhanced_monitoring(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::STREAM_005) {
+                aws::streams::check_stream_firehose_buffering(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::MSG_001) {
+                aws::messaging::check_sqs_short_polling(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::MSG_002) {
+                aws::messaging::check_sqs_message_retention_period(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::MSG_003) {
+                aws::messaging::check_sqs_fifo_queue(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::MSG_004) {
+                aws::messaging::check_eventbridge_archive_retention(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::MSG_005) {
+                aws::messaging::check_sns_lambda_filter_policy(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ELASTICACHE_001) {
+                aws::elasticache::check_elasticache_previous_generation(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ELASTICACHE_002) {
+                aws::elasticache::check_elasticache_graviton(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ELASTICACHE_003) {
+                aws::elasticache::check_elasticache_replica_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::OPENSEARCH_001) {
+                aws::opensearch::check_opensearch_previous_generation(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::OPENSEARCH_002) {
+                aws::opensearch::check_opensearch_graviton(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::OPENSEARCH_003) {
+                aws::opensearch::check_opensearch_dedicated_master(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::OPENSEARCH_004) {
+                aws::opensearch::check_opensearch_gp2_volume(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::OPENSEARCH_005) {
+                aws::opensearch::check_opensearch_warm_storage(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CLOUDFRONT_001) {
+                aws::cloudfront::check_cloudfront_price_class(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CLOUDFRONT_002) {
+                aws::cloudfront::check_cloudfront_compression(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CLOUDFRONT_003) {
+                aws::cloudfront::check_cloudfront_caching_disabled(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CLOUDFRONT_004) {
+                aws::cloudfront::check_cloudfront_realtime_logs(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CLOUDFRONT_005) {
+                aws::cloudfront::check_cloudfront_log_bucket_lifecycle(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ECR_001) {
+                aws::ecr::check_ecr_lifecycle_policy(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ECR_002) {
+                aws::ecr::check_ecr_untagged_image_expiry(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::FS_001) {
+                aws::filesystems::check_efs_lifecycle_policy(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::FS_002) {
+                aws::filesystems::check_efs_provisioned_throughput(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::FS_003) {
+                aws::filesystems::check_efs_one_zone(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::FS_004) {
+                aws::filesystems::check_fsx_backup_retention(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SECRET_001) {
+                aws::secrets::check_secrets_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SECRET_002) {
+                aws::secrets::check_kms_key_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SECRET_003) {
+                aws::secrets::check_secrets_rotation(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SECRET_004) {
+                aws::secrets::check_kms_key_single_use(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SECRET_005) {
+                aws::secrets::check_ssm_advanced_parameter(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::GLUE_001) {
+                aws::glue::check_glue_worker_type(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::GLUE_002) {
+                aws::glue::check_glue_number_of_workers(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::GLUE_003) {
+                aws::glue::check_glue_execution_class(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::GLUE_004) {
+                aws::glue::check_glue_crawler_schedule(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ATHENA_001) {
+                aws::athena::check_athena_bytes_scanned_cutoff(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::ATHENA_002) {
+                aws::athena::check_athena_enforce_configuration(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SAGEMAKER_001) {
+                aws::sagemaker::check_sagemaker_serverless_inference(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SAGEMAKER_002) {
+                aws::sagemaker::check_sagemaker_notebook_auto_stop(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SAGEMAKER_003) {
+                aws::sagemaker::check_sagemaker_endpoint_autoscaling(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SAGEMAKER_004) {
+                aws::sagemaker::check_sagemaker_domain_idle_shutdown(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::SCHEDULE_001) {
+                aws::schedules::check_schedule_frequency(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::BACKUP_001) {
+                aws::backup::check_backup_retention(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::BACKUP_002) {
+                aws::backup::check_backup_cold_storage(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::BACKUP_003) {
+                aws::backup::check_backup_frequency(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::BACKUP_004) {
+                aws::backup::check_snapshot_deletion_policy(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CODEBUILD_001) {
+                aws::codebuild::check_codebuild_compute_type(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CODEBUILD_002) {
+                aws::codebuild::check_codebuild_arm_image(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CODEBUILD_003) {
+                aws::codebuild::check_codebuild_cache(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CODEBUILD_004) {
+                aws::codebuild::check_codebuild_log_group_retention(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CODEPIPELINE_001) {
+                aws::codepipeline::check_codepipeline_frequent_v2(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::CODEPIPELINE_002) {
+                aws::codepipeline::check_codepipeline_infrequent_v1(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::REDSHIFT_001) {
+                aws::redshift::check_redshift_node_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::REDSHIFT_002) {
+                aws::redshift::check_redshift_serverless_base_capacity(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::REDSHIFT_003) {
+                aws::redshift::check_redshift_manual_snapshot_retention(
+                    self.infra_template,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::DOCDB_001) {
+                aws::docdb::check_docdb_instance_count(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::DOCDB_002) {
+                aws::docdb::check_docdb_graviton(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+
+            if rule_context.enabled(RuleType::TAG_001) {
+                aws::tagging::check_tagging_policy(
+                    self.infra_template,
+                    &rule_context,
+                    self.error_reporter,
+                    self.line_marker,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_cfn {
+    use crate::rules::violations::Violation;
+
+    struct ExpectedViolation {
+        code: String,
+        resource: String,
+        message: String,
+    }
+
+    impl ExpectedViolation {
+        fn new(violation: &dyn Violation, resource: &str) -> Self {
+            Self {
+                code: violation.code(),
+                resource: resource.to_string(),
+                message: violation.message(),
+            }
+        }
+    }
+
+    struct ExpectedViolations(Vec<ExpectedViolation>);
+
+    impl ExpectedViolations {
+        fn new(violations: Vec<ExpectedViolation>) -> Self {
+            Self(violations)
+        }
+
+        fn assert_all_match(&self, actual: &str) {
+            let actual_lines: Vec<&str> = actual
+                .lines()
+                .filter(|line| {
+                    !line.is_empty()
+                        && !line.contains("src/fixtures/aws/")
+                        && !line.starts_with("note: ")
+                })
+                .collect();
+            for (i, expected) in self.0.iter().enumerate() {
+                let expected_str = format!(
+                    "{}:{}:{}",
+                    expected.code, expected.resource, expected.message
+                );
+                let actual_line = actual_lines.get(i).unwrap_or(&"");
+                assert_eq!(
+                    actual_line, &expected_str,
+                    "\nExpected: {}\nActual: {}",
+                    expected_str, actual_line
+                );
+            }
+        }
+    }
+
+    mod cfn_tests {
+        use super::{ExpectedViolation, ExpectedViolations};
+        use crate::checker::Checker;
+        use crate::error_reporter::ErrorReporter;
+        use crate::parsers::cfn::{parse_cloudformation, CloudFormation};
+        use crate::parsers::config::{
+            Config, Environment, RuleConfig, RuleType, RuleTypeConfigDetail, TagMatch, TagPolicy,
+            TagRequirement, ThresholdValue,
+        };
+        use crate::parsers::get_yaml_line_marker;
+        use crate::parsers::iac::InfratructureTemplate;
+        use crate::parsers::YamlLineMarker;
+        use crate::rules::violations::{
+            ApiGatewayViolation, AthenaViolation, BackupViolation, CloudFrontViolation,
+            CloudWatchViolation, CodeBuildViolation, CodePipelineViolation, DocDbViolation,
+            EcrViolation, EcsViolation, ElastiCacheViolation, FileSystemViolation, GlueViolation,
+            LambdaViolation, MessagingViolation, OpenSearchViolation, RedshiftViolation,
+            SageMakerViolation, ScheduleViolation, SecretsViolation, StepFunctionsViolation,
+            StreamViolation, TaggingViolation, Violation,
+        };
+        use regex::Regex;
+        use rstest::*;
+
+        #[fixture]
+        fn test_config() -> Config {
+            let mut default_rule_config = RuleConfig::default();
+            for rule in default_rule_config.rules.values_mut() {
+                rule.enabled = false;
+            }
+            default_rule_config.environments.insert(
+                "default".to_string(),
+                Some(Environment {
+                    rules: default_rule_config.rules.clone(),
+                    ..Default::default()
+                }),
+            );
+
+            Config {
+                cloudformation: Some(default_rule_config),
+            }
+        }
+
+        fn get_error_reporter(path: &str) -> ErrorReporter {
+            ErrorReporter::new(&format!("src/fixtures/aws/{}", path))
+        }
+
+        fn get_cloudformation(path: &str) -> CloudFormation {
+            let mut parsed_cfn =
+                parse_cloudformation(&format!("src/fixtures/aws/{}", path)).unwrap();
+            parsed_cfn.resolve_parameters(None, "default");
+            parsed_cfn
+        }
+
+        fn get_line_marker(path: &str) -> YamlLineMarker {
+            get_yaml_line_marker(&format!("src/fixtures/aws/{}", path)).unwrap()
+        }
+
+        fn enable_rule(
+            config: &mut Config,
+            rule_type: RuleType,
+            config_detail: Option<RuleTypeConfigDetail>,
+        ) {
+            if let Some(cloudformation) = &mut config.cloudformation {
+                if let Some(Some(env)) = cloudformation.environments.get_mut("default") {
+                    if let Some(rule) = env.rules.get_mut(&rule_type) {
+                        rule.enabled = true;
+                        if let Some(config_detail) = config_detail {
+                            rule.config_detail = config_detail.clone();
+                        }
+                    }
+                }
+            }
+        }
+
+        struct TestContext {
+            config: Config,
+            error_reporter: ErrorReporter,
+            infra_template: InfratructureTemplate,
+            line_marker: YamlLineMarker,
+        }
+
+        impl TestContext {
+            fn new(
+                template_name: &str,
+                rule_type: RuleType,
+                config_detail: Option<RuleTypeConfigDetail>,
+            ) -> Self {
+                let mut config = test_config(); // Use existing fixture
+                enable_rule(&mut config, rule_type, config_detail);
+
+                Self {
+                    config,
+                    error_reporter: get_error_reporter(template_name),
+                    infra_template: InfratructureTemplate::new(Some(get_cloudformation(
+                        template_name,
+                    ))),
+                    line_marker: get_line_marker(template_name),
+                }
+            }
+
+            fn create_checker(&mut self) -> Checker<'_, YamlLineMarker> {
+                Checker::new(
+                    &self.config,
+                    &mut self.error_reporter,
+                    &self.infra_template,
+                    &self.line_marker,
+                    "default",
+                )
+            }
+
+            /// Runs the checks and asserts that `violation` is reported for exactly `resources`.
+            fn assert_violations(&mut self, violation: &dyn Violation, resources: &[&str]) {
+                self.create_checker().run_checks();
+
+                let rendered = self.error_reporter.render_errors();
+                let expected = ExpectedViolations::new(
+                    resources
+                        .iter()
+                        .map(|resource| ExpectedViolation::new(violation, resource))
+                        .collect(),
+                );
+                expected.assert_all_match(&rendered);
+                assert_eq!(rendered.matches(&violation.code()).count(), resources.len());
+            }
+        }
+
+        #[fixture]
+        fn setup_checker() -> impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext {
+            |template_name, rule_type, config_detail: Option<RuleTypeConfigDetail>| {
+                TestContext::new(template_name, rule_type, config_detail)
+            }
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-testing.yaml",
+            RuleType::LAMBDA_003,
+            Some(RuleTypeConfigDetail::Values {
+                values: vec![String::from("tag1")]
+            }),
+            LambdaViolation::MissingTag
+        )]
+        #[case(
+            "cfn-testing.yaml",
+            RuleType::LAMBDA_002,
+            None,
+            LambdaViolation::ARMArchitecture
+        )]
+        #[case(
+            "cfn-testing.yaml",
+            RuleType::LAMBDA_001,
+            None,
+            LambdaViolation::MissingLogGroup
+        )]
+        fn test_lambda_001_002_003(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: LambdaViolation,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            let mut checker = context.create_checker();
+            checker.run_checks();
+
+            let expected = ExpectedViolations::new(vec![
+                ExpectedViolation::new(&violation, "MyLambdaFunction"),
+                ExpectedViolation::new(&violation, "MyLambdaFunction2"),
+            ]);
+            expected.assert_all_match(&context.error_reporter.render_errors());
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-lambda-examples.yaml",
+            RuleType::LAMBDA_004,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(0) }),
+            LambdaViolation::MaximumRetryAttempts
+        )]
+        fn test_lambda_004(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: LambdaViolation,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            let mut checker = context.create_checker();
+            checker.run_checks();
+
+            let expected = ExpectedViolations::new(vec![
+                ExpectedViolation::new(&violation, "MyLambdaFunction"),
+                ExpectedViolation::new(&violation, "MyLambdaFunction3"),
+            ]);
+            expected.assert_all_match(&context.error_reporter.render_errors());
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-lambda-examples.yaml",
+            RuleType::LAMBDA_007,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Float(0.5) }),
+            LambdaViolation::PowertoolsLoggerSampleRate
+        )]
+        fn test_lambda_005_006_007(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: LambdaViolation,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            let mut checker = context.create_checker();
+            checker.run_checks();
+
+            let expected = ExpectedViolations::new(vec![ExpectedViolation::new(
+                &violation,
+                "MyLambdaFunction",
+            )]);
+            expected.assert_all_match(&context.error_reporter.render_errors());
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-lambda-sizing.yaml",
+            RuleType::LAMBDA_008,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(1024) }),
+            LambdaViolation::MemorySize,
+            vec!["MyLambdaFunction"]
+        )]
+        #[case(
+            "cfn-lambda-sizing.yaml",
+            RuleType::LAMBDA_009,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(600) }),
+            LambdaViolation::ApiTimeout,
+            vec!["MyLambdaFunction"]
+        )]
+        #[case(
+            "cfn-lambda-sizing.yaml",
+            RuleType::LAMBDA_010,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(512) }),
+            LambdaViolation::EphemeralStorage,
+            vec!["MyLambdaFunction"]
+        )]
+        #[case(
+            "cfn-lambda-sizing.yaml",
+            RuleType::LAMBDA_011,
+            None,
+            LambdaViolation::ProvisionedConcurrency,
+            vec!["MyLambdaFunction", "MyLambdaVersion"]
+        )]
+        fn test_lambda_008_009_010_011(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: LambdaViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-lambda-event-sources.yaml",
+            RuleType::LAMBDA_012,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(10) }),
+            LambdaViolation::SmallBatchSize,
+            vec![
+                "MyLambdaFunction",
+                "MyEventSourceMapping",
+                "MyEventSourceMapping3",
+                "MyEventSourceMapping4"
+            ]
+        )]
+        #[case(
+            "cfn-lambda-event-sources.yaml",
+            RuleType::LAMBDA_013,
+            None,
+            LambdaViolation::MissingFilterCriteria,
+            vec![
+                "MyLambdaFunction",
+                "MyEventSourceMapping",
+                "MyEventSourceMapping3",
+                "MyEventSourceMapping4"
+            ]
+        )]
+        #[case(
+            "cfn-lambda-event-sources.yaml",
+            RuleType::LAMBDA_014,
+            None,
+            LambdaViolation::UnboundedStreamRetries,
+            vec!["MyEventSourceMapping", "MyEventSourceMapping3", "MyEventSourceMapping4"]
+        )]
+        #[case(
+            "cfn-lambda-event-sources.yaml",
+            RuleType::LAMBDA_015,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(4) }),
+            LambdaViolation::SqsRetryAmplification,
+            vec!["MyLambdaFunction", "MyEventSourceMapping2"]
+        )]
+        #[case(
+            "cfn-lambda-event-sources.yaml",
+            RuleType::LAMBDA_015,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(5) }),
+            LambdaViolation::SqsRetryAmplification,
+            vec![]
+        )]
+        fn test_lambda_event_sources(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: LambdaViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        fn test_lambda_014_bisect_note(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context =
+                setup_checker("cfn-lambda-event-sources.yaml", RuleType::LAMBDA_014, None);
+            context.create_checker().run_checks();
+
+            // Only mappings without BisectBatchOnFunctionError get the note
+            let rendered = context.error_reporter.render_errors();
+            assert_eq!(
+                rendered
+                    .matches("note: BisectBatchOnFunctionError is not enabled either\n")
+                    .count(),
+                2
+            );
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-testing.yaml",
+            RuleType::CW_001,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(14) }),
+            CloudWatchViolation::LogRetentionTooLong,
+        )]
+        #[case(
+            "cfn-testing.yaml",
+            RuleType::CW_002,
+            None,
+            CloudWatchViolation::NoLogRetention
+        )]
+        fn test_cw_001_002(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: CloudWatchViolation,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            let mut checker = context.create_checker();
+            checker.run_checks();
+
+            let log_group_name = if violation == CloudWatchViolation::LogRetentionTooLong {
+                "MyLogGroup"
+            } else {
+                "MyLogGroup2"
+            };
+            let expected =
+                ExpectedViolations::new(vec![ExpectedViolation::new(&violation, log_group_name)]);
+            expected.assert_all_match(&context.error_reporter.render_errors());
+        }
+
+        #[rstest]
+        fn test_lambda_001_linked_log_group(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context =
+                setup_checker("cfn-lambda-log-groups.yaml", RuleType::LAMBDA_001, None);
+            context.assert_violations(&LambdaViolation::MissingLogGroup, &["MyLambdaFunction4"]);
+        }
+
+        #[rstest]
+        fn test_cw_002_names_owning_function(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker("cfn-lambda-log-groups.yaml", RuleType::CW_002, None);
+            context.assert_violations(&CloudWatchViolation::NoLogRetention, &["MyLogGroup"]);
+            assert!(context
+                .error_reporter
+                .render_errors()
+                .contains("note: log group of Lambda function MyLambdaFunction\n"));
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-testing.yaml",
+            RuleType::CW_003,
+            None,
+            CloudWatchViolation::InfrequentAccessLogGroupClass
+        )]
+        fn test_cw_003(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: CloudWatchViolation,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            let mut checker = context.create_checker();
+            checker.run_checks();
+
+            let expected = ExpectedViolations::new(vec![
+                ExpectedViolation::new(&violation, "MyLogGroup"),
+                ExpectedViolation::new(&violation, "MyLogGroup2"),
+            ]);
+            expected.assert_all_match(&context.error_reporter.render_errors());
+        }
+        #[rstest]
+        fn test_cw_003_incompatible_consumers(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker("cfn-cloudwatch.yaml", RuleType::CW_003, None);
+            context.create_checker().run_checks();
+
+            let rendered = context.error_reporter.render_errors();
+            let consumers = CloudWatchViolation::InfrequentAccessIncompatibleConsumers;
+            let expected = ExpectedViolations::new(vec![
+                ExpectedViolation::new(&consumers, "MyLogGroup2"),
+                ExpectedViolation::new(&consumers, "MyLogGroup3"),
+                ExpectedViolation::new(
+                    &CloudWatchViolation::InfrequentAccessLogGroupClass,
+                    "MyLogGroup4",
+                ),
+            ]);
+            expected.assert_all_match(&rendered);
+            assert!(rendered.contains(
+                "note: consumed by MyMetricFilter2, MySubscriptionFilter, MySubscriptionFilter2\n"
+            ));
+            assert!(rendered.contains("note: consumed by MyMetricFilter3\n"));
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-cloudwatch.yaml",
+            RuleType::CW_004,
+            None,
+            CloudWatchViolation::HighResolutionAlarm,
+            vec!["MyAlarm", "MyMetricMathAlarm"]
+        )]
+        #[case(
+            "cfn-cloudwatch.yaml",
+            RuleType::CW_005,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(2) }),
+            CloudWatchViolation::TooManyAlarms,
+            vec!["MyLambdaFunction"]
+        )]
+        #[case(
+            "cfn-cloudwatch.yaml",
+            RuleType::CW_006,
+            None,
+            CloudWatchViolation::MetricFilterOnInfrequentAccess,
+            vec!["MyMetricFilter"]
+        )]
+        #[case(
+            "cfn-cloudwatch.yaml",
+            RuleType::CW_007,
+            None,
+            CloudWatchViolation::UnfilteredSubscription,
+            vec!["MySubscriptionFilter"]
+        )]
+        #[case(
+            "cfn-cloudwatch.yaml",
+            RuleType::CW_008,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(1) }),
+            CloudWatchViolation::DashboardFreeTier,
+            vec!["MyDashboard2"]
+        )]
+        fn test_cloudwatch(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: CloudWatchViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-ecs.yaml",
+            RuleType::ECS_001,
+            None,
+            EcsViolation::ARMArchitecture,
+            vec!["MyTaskDefinition", "MyTaskDefinition2"]
+        )]
+        #[case(
+            "cfn-ecs.yaml",
+            RuleType::ECS_002,
+            None,
+            EcsViolation::FargateSpot,
+            vec!["MyService", "MyService2"]
+        )]
+        #[case(
+            "cfn-ecs.yaml",
+            RuleType::ECS_003,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(1024) }),
+            EcsViolation::OversizedTask,
+            vec!["MyTaskDefinition", "MyTaskDefinition2"]
+        )]
+        #[case(
+            "cfn-ecs.yaml",
+            RuleType::ECS_004,
+            None,
+            EcsViolation::ContainerInsights,
+            vec!["MyCluster"]
+        )]
+        #[case(
+            "cfn-ecs.yaml",
+            RuleType::ECS_005,
+            None,
+            EcsViolation::AwslogsNoLogRetention,
+            vec!["MyTaskDefinition", "MyTaskDefinition2"]
+        )]
+        fn test_ecs(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: EcsViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+        #[rstest]
+        #[case(
+            "cfn-apigateway.yaml",
+            RuleType::APIGW_001,
+            None,
+            ApiGatewayViolation::HttpApiCandidate,
+            vec!["MyApi2"]
+        )]
+        #[case(
+            "cfn-apigateway.yaml",
+            RuleType::APIGW_002,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Float(1.6) }),
+            ApiGatewayViolation::CacheClusterSize,
+            vec!["MyApi"]
+        )]
+        #[case(
+            "cfn-apigateway.yaml",
+            RuleType::APIGW_003,
+            None,
+            ApiGatewayViolation::ExecutionLogging,
+            vec!["MyApi2"]
+        )]
+        #[case(
+            "cfn-apigateway.yaml",
+            RuleType::APIGW_004,
+            None,
+            ApiGatewayViolation::AccessLogNoRetention,
+            vec!["MyApi2", "MyHttpApi"]
+        )]
+        fn test_apigateway(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: ApiGatewayViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+        #[rstest]
+        #[case(
+            "cfn-stepfunctions.yaml",
+            RuleType::SFN_001,
+            None,
+            StepFunctionsViolation::ExpressWorkflow,
+            vec!["MyStateMachine", "MyStateMachine3"]
+        )]
+        #[case(
+            "cfn-stepfunctions.yaml",
+            RuleType::SFN_002,
+            None,
+            StepFunctionsViolation::LoggingExecutionData,
+            vec!["MyStateMachine"]
+        )]
+        #[case(
+            "cfn-stepfunctions.yaml",
+            RuleType::SFN_003,
+            None,
+            StepFunctionsViolation::Tracing,
+            vec!["MyStateMachine", "MyStateMachine3"]
+        )]
+        fn test_stepfunctions(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: StepFunctionsViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+        #[rstest]
+        #[case(
+            "cfn-streams.yaml",
+            RuleType::STREAM_001,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(10) }),
+            StreamViolation::ProvisionedShardCount,
+            vec!["MyStream"]
+        )]
+        #[case(
+            "cfn-streams.yaml",
+            RuleType::STREAM_002,
+            None,
+            StreamViolation::OnDemandStream,
+            vec!["MyStream2"]
+        )]
+        #[case(
+            "cfn-streams.yaml",
+            RuleType::STREAM_003,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(24) }),
+            StreamViolation::ExtendedRetention,
+            vec!["MyStream"]
+        )]
+        #[case(
+            "cfn-streams.yaml",
+            RuleType::STREAM_004,
+            None,
+            StreamViolation::EnhancedMonitoring,
+            vec!["MyMskCluster"]
+        )]
+        #[case(
+            "cfn-streams.yaml",
+            RuleType::STREAM_005,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(300) }),
+            StreamViolation::FirehoseSmallBuffer,
+            vec!["MyDeliveryStream"]
+        )]
+        fn test_streams(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: StreamViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+        #[rstest]
+        #[case(
+            "cfn-messaging.yaml",
+            RuleType::MSG_001,
+            None,
+            MessagingViolation::ShortPolling,
+            vec!["MyQueue", "MyQueue2"]
+        )]
+        #[case(
+            "cfn-messaging.yaml",
+            RuleType::MSG_002,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(345600) }),
+            MessagingViolation::MessageRetentionPeriod,
+            vec!["MyQueue"]
+        )]
+        #[case(
+            "cfn-messaging.yaml",
+            RuleType::MSG_003,
+            Some(RuleTypeConfigDetail::Values { values: vec![String::from("MyQueue3")] }),
+            MessagingViolation::FifoQueue,
+            vec!["MyQueue2"]
+        )]
+        #[case(
+            "cfn-messaging.yaml",
+            RuleType::MSG_004,
+            None,
+            MessagingViolation::ArchiveRetention,
+            vec!["MyArchive"]
+        )]
+        #[case(
+            "cfn-messaging.yaml",
+            RuleType::MSG_005,
+            None,
+            MessagingViolation::SnsFilterPolicy,
+            vec!["MyTopic", "MySubscription", "MyLambdaFunction"]
+        )]
+        fn test_messaging(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: MessagingViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        fn test_ecs_005_names_containers(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker("cfn-ecs.yaml", RuleType::ECS_005, None);
+            context.assert_violations(
+                &EcsViolation::AwslogsNoLogRetention,
+                &["MyTaskDefinition", "MyTaskDefinition2"],
+            );
+            let rendered = context.error_reporter.render_errors();
+            assert!(rendered.contains("note: awslogs containers app\n"));
+            assert!(rendered.contains("note: awslogs containers app, sidecar\n"));
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-elasticache.yaml",
+            RuleType::ELASTICACHE_001,
+            None,
+            ElastiCacheViolation::PreviousGeneration,
+            vec!["MyCacheCluster"]
+        )]
+        #[case(
+            "cfn-elasticache.yaml",
+            RuleType::ELASTICACHE_002,
+            None,
+            ElastiCacheViolation::NonGraviton,
+            vec!["MyReplicationGroup"]
+        )]
+        #[case(
+            "cfn-elasticache.yaml",
+            RuleType::ELASTICACHE_003,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(1) }),
+            ElastiCacheViolation::TooManyReplicas,
+            vec!["MyReplicationGroup"]
+        )]
+        fn test_elasticache(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: ElastiCacheViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-opensearch.yaml",
+            RuleType::OPENSEARCH_001,
+            None,
+            OpenSearchViolation::PreviousGeneration,
+            vec!["MyDomain"]
+        )]
+        #[case(
+            "cfn-opensearch.yaml",
+            RuleType::OPENSEARCH_002,
+            None,
+            OpenSearchViolation::NonGraviton,
+            vec!["MyDomain"]
+        )]
+        #[case(
+            "cfn-opensearch.yaml",
+            RuleType::OPENSEARCH_003,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(3) }),
+            OpenSearchViolation::DedicatedMasterOnSmallDomain,
+            vec!["MyDomain"]
+        )]
+        #[case(
+            "cfn-opensearch.yaml",
+            RuleType::OPENSEARCH_004,
+            None,
+            OpenSearchViolation::Gp2Volume,
+            vec!["MyDomain"]
+        )]
+        #[case(
+            "cfn-opensearch.yaml",
+            RuleType::OPENSEARCH_005,
+            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(1024) }),
+            OpenSearchViolation::NoWarmStorage,
+            vec!["MyDomain2"]
+        )]
+        fn test_opensearch(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: OpenSearchViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-cloudfront.yaml",
+            RuleType::CLOUDFRONT_001,
+            Some(RuleTypeConfigDetail::Values {
+                values: vec![String::from("PriceClass_100"), String::from("PriceClass_200")]
+            }),
+            CloudFrontViolation::PriceClass,
+            vec!["MyDistribution", "MyDistribution3"]
+        )]
+        #[case(
+            "cfn-cloudfront.yaml",
+            RuleType::CLOUDFRONT_002,
+            None,
+            CloudFrontViolation::Compression,
+            vec!["MyDistribution", "MyDistribution2"]
+        )]
+        #[case(
+            "cfn-cloudfront.yaml",
+            RuleType::CLOUDFRONT_003,
+            None,
+            CloudFrontViolation::CachingDisabled,
+            vec!["MyDistribution", "MyDistribution2"]
+        )]
+        #[case(
+            "cfn-cloudfront.yaml",
+            RuleType::CLOUDFRONT_004,
+            None,
+            CloudFrontViolation::RealtimeLogs,
+            vec!["MyDistribution2"]
+        )]
+        #[case(
+            "cfn-cloudfront.yaml",
+            RuleType::CLOUDFRONT_005,
+            None,
+            CloudFrontViolation::LogBucketLifecycle,
+            vec!["MyDistribution"]
+        )]
+        fn test_cloudfront(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: CloudFrontViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-ecr.yaml",
+            RuleType::ECR_001,
+            None,
+            EcrViolation::MissingLifecyclePolicy,
+            vec!["MyRepository"]
+        )]
+        #[case(
+            "cfn-ecr.yaml",
+            RuleType::ECR_002,
+            None,
+            EcrViolation::UntaggedImageExpiry,
+            vec!["MyRepository2", "MyRepository3"]
+        )]
+        #[case(
+            "cfn-ecr.yaml",
+            RuleType::ECR_002,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(5)
+            }),
+            EcrViolation::UntaggedImageExpiry,
+            vec!["MyRepository2", "MyRepository3", "MyRepository4"]
+        )]
+        fn test_ecr(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: EcrViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        fn test_ecr_names_image_functions(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker("cfn-ecr.yaml", RuleType::ECR_001, None);
+            context.assert_violations(&EcrViolation::MissingLifecyclePolicy, &["MyRepository"]);
+            assert!(context
+                .error_reporter
+                .render_errors()
+                .contains("note: images used by Lambda functions MyLambdaFunction\n"));
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-filesystems.yaml",
+            RuleType::FS_001,
+            None,
+            FileSystemViolation::EfsLifecyclePolicy,
+            vec!["MyFileSystem", "MyFileSystem2"]
+        )]
+        #[case(
+            "cfn-filesystems.yaml",
+            RuleType::FS_002,
+            None,
+            FileSystemViolation::EfsProvisionedThroughput,
+            vec!["MyFileSystem"]
+        )]
+        #[case(
+            "cfn-filesystems.yaml",
+            RuleType::FS_003,
+            None,
+            FileSystemViolation::EfsOneZone,
+            vec!["MyFileSystem", "MyFileSystem2"]
+        )]
+        #[case(
+            "cfn-filesystems.yaml",
+            RuleType::FS_004,
+            None,
+            FileSystemViolation::FsxBackupRetention,
+            vec!["MyFsxFileSystem"]
+        )]
+        #[case(
+            "cfn-filesystems.yaml",
+            RuleType::FS_004,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(3)
+            }),
+            FileSystemViolation::FsxBackupRetention,
+            vec!["MyFsxFileSystem", "MyFsxFileSystem2"]
+        )]
+        fn test_filesystems(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: FileSystemViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        fn test_efs_names_mounting_functions(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker("cfn-filesystems.yaml", RuleType::FS_002, None);
+            context.assert_violations(
+                &FileSystemViolation::EfsProvisionedThroughput,
+                &["MyFileSystem"],
+            );
+            assert!(context
+                .error_reporter
+                .render_errors()
+                .contains("note: mounted by Lambda functions MyLambdaFunction\n"));
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-secrets.yaml",
+            RuleType::SECRET_001,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(1)
+            }),
+            SecretsViolation::TooManySecrets,
+            vec!["MySecret2", "MySecret3"]
+        )]
+        #[case(
+            "cfn-secrets.yaml",
+            RuleType::SECRET_002,
+            None,
+            SecretsViolation::TooManyKmsKeys,
+            vec!["MyKey3"]
+        )]
+        #[case(
+            "cfn-secrets.yaml",
+            RuleType::SECRET_003,
+            None,
+            SecretsViolation::NonRotatingSecret,
+            vec!["MySecret"]
+        )]
+        #[case(
+            "cfn-secrets.yaml",
+            RuleType::SECRET_004,
+            None,
+            SecretsViolation::SingleUseKmsKey,
+            vec!["MyKey"]
+        )]
+        #[case(
+            "cfn-secrets.yaml",
+            RuleType::SECRET_005,
+            None,
+            SecretsViolation::AdvancedParameter,
+            vec!["MyParameter", "MyParameter3"]
+        )]
+        fn test_secrets(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: SecretsViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
+        }
+
+        #[rstest]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::GLUE_001,
+            None,
+            GlueViolation::LargeWorkerType,
+            vec!["MyGlueJob"]
+        )]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::GLUE_002,
+            None,
+            GlueViolation::TooManyWorkers,
+            vec!["MyGlueJob"]
+        )]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::GLUE_002,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(2)
+            }),
+            GlueViolation::TooManyWorkers,
+            vec!["MyGlueJob", "MyGlueJob2"]
+        )]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::GLUE_003,
+            None,
+            GlueViolation::StandardExecutionClass,
+            vec!["MyGlueJob"]
+        )]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::GLUE_004,
+            None,
+            GlueViolation::FrequentCrawler,
+            vec!["MyCrawler"]
+        )]
+        fn test_glue(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: GlueViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
         }
 
-        fn get_line_marker(path: &str) -> YamlLineMarker {
-            get_yaml_line_marker(&format!("src/fixtures/aws/{}", path)).unwrap()
+        #[rstest]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::ATHENA_001,
+            None,
+            AthenaViolation::MissingBytesScannedCutoff,
+            vec!["MyWorkGroup"]
+        )]
+        #[case(
+            "cfn-glue-athena.yaml",
+            RuleType::ATHENA_002,
+            None,
+            AthenaViolation::ConfigurationNotEnforced,
+            vec!["MyWorkGroup4"]
+        )]
+        fn test_athena(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: AthenaViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
         }
 
-        fn enable_rule(
-            config: &mut Config,
-            rule_type: RuleType,
-            config_detail: Option<RuleTypeConfigDetail>,
+        #[rstest]
+        #[case(
+            "cfn-sagemaker.yaml",
+            RuleType::SAGEMAKER_001,
+            None,
+            SageMakerViolation::RealTimeEndpoint,
+            vec!["MyEndpointConfig"]
+        )]
+        #[case(
+            "cfn-sagemaker.yaml",
+            RuleType::SAGEMAKER_002,
+            None,
+            SageMakerViolation::GpuNotebookWithoutAutoStop,
+            vec!["MyNotebook"]
+        )]
+        #[case(
+            "cfn-sagemaker.yaml",
+            RuleType::SAGEMAKER_003,
+            None,
+            SageMakerViolation::UnscaledEndpoint,
+            vec!["MyEndpointConfig"]
+        )]
+        #[case(
+            "cfn-sagemaker.yaml",
+            RuleType::SAGEMAKER_004,
+            None,
+            SageMakerViolation::DomainIdleShutdown,
+            vec!["MyDomain"]
+        )]
+        fn test_sagemaker(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: SageMakerViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
         ) {
-            if let Some(cloudformation) = &mut config.cloudformation {
-                if let Some(Some(env)) = cloudformation.environments.get_mut("default") {
-                    if let Some(rule) = env.get_mut(&rule_type) {
-                        rule.enabled = true;
-                        if let Some(config_detail) = config_detail {
-                            rule.config_detail = config_detail.clone();
-                        }
-                    }
-                }
-            }
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
         }
 
-        struct TestContext {
-            config: Config,
-            error_reporter: ErrorReporter,
-            infra_template: InfratructureTemplate,
-            line_marker: YamlLineMarker,
+        #[rstest]
+        #[case(None, vec!["MyLambdaFunction", "MyRule", "MySchedule", "MyStateMachine"])]
+        #[case(
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(12)
+            }),
+            vec!["MyLambdaFunction", "MyRule", "MyRule2", "MySchedule", "MyStateMachine"]
+        )]
+        fn test_schedule_001(
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context =
+                setup_checker("cfn-schedules.yaml", RuleType::SCHEDULE_001, config_detail);
+            context.assert_violations(&ScheduleViolation::FrequentSchedule, &resources);
+            let errors = context.error_reporter.render_errors();
+            assert!(
+                errors.contains("note: about 43830 invocations per month of MyLambdaFunction\n")
+            );
+            assert!(
+                errors.contains("note: about 21915 invocations per month of MyLambdaFunction2\n")
+            );
+            assert!(errors.contains("note: about 43830 invocations per month\n"));
+            assert!(errors.contains("note: about 43830 invocations per month of MyStateMachine\n"));
         }
 
-        impl TestContext {
-            fn new(
-                template_name: &str,
-                rule_type: RuleType,
-                config_detail: Option<RuleTypeConfigDetail>,
-            ) -> Self {
-                let mut config = test_config(); // Use existing fixture
-                enable_rule(&mut config, rule_type, config_detail);
-
-                Self {
-                    config,
-                    error_reporter: get_error_reporter(template_name),
-                    infra_template: InfratructureTemplate {
-                        cloudformation: Some(get_cloudformation(template_name)),
-                    },
-                    line_marker: get_line_marker(template_name),
-                }
-            }
-
-            fn create_checker(&mut self) -> Checker<'_, YamlLineMarker> {
-                Checker::new(
-                    &self.config,
-                    &mut self.error_reporter,
-                    &self.infra_template,
-                    &self.line_marker,
-                    "default",
-                )
-            }
+        #[rstest]
+        fn test_lambda_008_scheduled_invocations(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker("cfn-schedules.yaml", RuleType::LAMBDA_008, None);
+            context.assert_violations(&LambdaViolation::MemorySize, &["MyLambdaFunction"]);
+            assert!(context
+                .error_reporter
+                .render_errors()
+                .contains("note: scheduled about 43860 times per month\n"));
         }
 
-        #[fixture]
-        fn setup_checker() -> impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext {
-            |template_name, rule_type, config_detail: Option<RuleTypeConfigDetail>| {
-                TestContext::new(template_name, rule_type, config_detail)
-            }
+        #[rstest]
+        #[case(
+            "cfn-backup.yaml",
+            RuleType::BACKUP_001,
+            None,
+            BackupViolation::RetentionPeriod,
+            vec!["MyBackupPlan", "MyBackupPlan2", "MyBackupVault"]
+        )]
+        #[case(
+            "cfn-backup.yaml",
+            RuleType::BACKUP_002,
+            None,
+            BackupViolation::MissingColdStorage,
+            vec!["MyBackupPlan"]
+        )]
+        #[case(
+            "cfn-backup.yaml",
+            RuleType::BACKUP_003,
+            None,
+            BackupViolation::FrequentBackups,
+            vec!["MyBackupPlan"]
+        )]
+        #[case(
+            "cfn-backup.yaml",
+            RuleType::BACKUP_004,
+            None,
+            BackupViolation::SnapshotOnDeletion,
+            vec!["MyDatabase", "MyVolume"]
+        )]
+        #[case(
+            "cfn-backup.yaml",
+            RuleType::BACKUP_004,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(300)
+            }),
+            BackupViolation::SnapshotOnDeletion,
+            vec!["MyDatabase"]
+        )]
+        fn test_backup(
+            #[case] template_name: &str,
+            #[case] rule_type: RuleType,
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] violation: BackupViolation,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(template_name, rule_type, config_detail);
+            context.assert_violations(&violation, &resources);
         }
 
         #[rstest]
         #[case(
-            "cfn-testing.yaml",
-            RuleType::LAMBDA_003,
-            Some(RuleTypeConfigDetail::Values {
-                values: vec![String::from("tag1")]
-            }),
-            LambdaViolation::MissingTag
+            "cfn-codebuild.yaml",
+            RuleType::CODEBUILD_001,
+            None,
+            CodeBuildViolation::LargeComputeType,
+            vec!["MyProject"]
         )]
         #[case(
-            "cfn-testing.yaml",
-            RuleType::LAMBDA_002,
+            "cfn-codebuild.yaml",
+            RuleType::CODEBUILD_002,
             None,
-            LambdaViolation::ARMArchitecture
+            CodeBuildViolation::X86Image,
+            vec!["MyProject"]
         )]
         #[case(
-            "cfn-testing.yaml",
-            RuleType::LAMBDA_001,
+            "cfn-codebuild.yaml",
+            RuleType::CODEBUILD_003,
             None,
-            LambdaViolation::MissingLogGroup
+            CodeBuildViolation::MissingCache,
+            vec!["MyProject", "MyProject3"]
         )]
-        fn test_lambda_001_002_003(
+        #[case(
+            "cfn-codebuild.yaml",
+            RuleType::CODEBUILD_004,
+            None,
+            CodeBuildViolation::NoLogRetention,
+            vec!["MyProject", "MyProject3"]
+        )]
+        fn test_codebuild(
             #[case] template_name: &str,
             #[case] rule_type: RuleType,
             #[case] config_detail: Option<RuleTypeConfigDetail>,
-            #[case] violation: LambdaViolation,
+            #[case] violation: CodeBuildViolation,
+            #[case] resources: Vec<&str>,
             setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
         ) {
             let mut context = setup_checker(template_name, rule_type, config_detail);
-            let mut checker = context.create_checker();
-            checker.run_checks();
-
-            let expected = ExpectedViolations::new(vec![
-                ExpectedViolation::new(&violation, "MyLambdaFunction"),
-                ExpectedViolation::new(&violation, "MyLambdaFunction2"),
-            ]);
-            expected.assert_all_match(&context.error_reporter.render_errors());
+            context.assert_violations(&violation, &resources);
         }
 
         #[rstest]
         #[case(
-            "cfn-lambda-examples.yaml",
-            RuleType::LAMBDA_004,
-            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(0) }),
-            LambdaViolation::MaximumRetryAttempts
+            "cfn-codebuild.yaml",
+            RuleType::CODEPIPELINE_001,
+            None,
+            CodePipelineViolation::FrequentV2Pipeline,
+            vec!["MyPipeline"]
         )]
-        fn test_lambda_004(
+        #[case(
+            "cfn-codebuild.yaml",
+            RuleType::CODEPIPELINE_002,
+            None,
+            CodePipelineViolation::InfrequentV1Pipeline,
+            vec!["MyPipeline2"]
+        )]
+        #[case(
+            "cfn-codebuild.yaml",
+            RuleType::CODEPIPELINE_002,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(2)
+            }),
+            CodePipelineViolation::InfrequentV1Pipeline,
+            vec![]
+        )]
+        fn test_codepipeline(
             #[case] template_name: &str,
             #[case] rule_type: RuleType,
             #[case] config_detail: Option<RuleTypeConfigDetail>,
-            #[case] violation: LambdaViolation,
+            #[case] violation: CodePipelineViolation,
+            #[case] resources: Vec<&str>,
             setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
         ) {
             let mut context = setup_checker(template_name, rule_type, config_detail);
-            let mut checker = context.create_checker();
-            checker.run_checks();
-
-            let expected = ExpectedViolations::new(vec![
-                ExpectedViolation::new(&violation, "MyLambdaFunction"),
-                ExpectedViolation::new(&violation, "MyLambdaFunction3"),
-            ]);
-            expected.assert_all_match(&context.error_reporter.render_errors());
+            context.assert_violations(&violation, &resources);
         }
 
         #[rstest]
         #[case(
-            "cfn-lambda-examples.yaml",
-            RuleType::LAMBDA_007,
-            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Float(0.5) }),
-            LambdaViolation::PowertoolsLoggerSampleRate
+            "cfn-redshift-docdb.yaml",
+            RuleType::REDSHIFT_001,
+            None,
+            RedshiftViolation::TooManyNodes,
+            vec!["MyRedshiftCluster"]
         )]
-        fn test_lambda_005_006_007(
+        #[case(
+            "cfn-redshift-docdb.yaml",
+            RuleType::REDSHIFT_002,
+            None,
+            RedshiftViolation::BaseCapacity,
+            vec!["MyWorkgroup"]
+        )]
+        #[case(
+            "cfn-redshift-docdb.yaml",
+            RuleType::REDSHIFT_003,
+            None,
+            RedshiftViolation::IndefiniteSnapshotRetention,
+            vec!["MyRedshiftCluster"]
+        )]
+        fn test_redshift(
             #[case] template_name: &str,
             #[case] rule_type: RuleType,
             #[case] config_detail: Option<RuleTypeConfigDetail>,
-            #[case] violation: LambdaViolation,
+            #[case] violation: RedshiftViolation,
+            #[case] resources: Vec<&str>,
             setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
         ) {
             let mut context = setup_checker(template_name, rule_type, config_detail);
-            let mut checker = context.create_checker();
-            checker.run_checks();
-
-            let expected = ExpectedViolations::new(vec![ExpectedViolation::new(
-                &violation,
-                "MyLambdaFunction",
-            )]);
-            expected.assert_all_match(&context.error_reporter.render_errors());
+            context.assert_violations(&violation, &resources);
         }
 
         #[rstest]
         #[case(
-            "cfn-testing.yaml",
-            RuleType::CW_001,
-            Some(RuleTypeConfigDetail::Threshold { threshold: ThresholdValue::Int(14) }),
-            CloudWatchViolation::LogRetentionTooLong,
+            "cfn-redshift-docdb.yaml",
+            RuleType::DOCDB_001,
+            None,
+            DocDbViolation::TooManyInstances,
+            vec!["MyDocDbCluster"]
         )]
         #[case(
-            "cfn-testing.yaml",
-            RuleType::CW_002,
+            "cfn-redshift-docdb.yaml",
+            RuleType::DOCDB_001,
+            Some(RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(2)
+            }),
+            DocDbViolation::TooManyInstances,
+            vec![]
+        )]
+        #[case(
+            "cfn-redshift-docdb.yaml",
+            RuleType::DOCDB_002,
             None,
-            CloudWatchViolation::NoLogRetention
+            DocDbViolation::NonGraviton,
+            vec!["MyDocDbInstance"]
         )]
-        fn test_cw_001_002(
+        fn test_docdb(
             #[case] template_name: &str,
             #[case] rule_type: RuleType,
             #[case] config_detail: Option<RuleTypeConfigDetail>,
-            #[case] violation: CloudWatchViolation,
+            #[case] violation: DocDbViolation,
+            #[case] resources: Vec<&str>,
             setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
         ) {
             let mut context = setup_checker(template_name, rule_type, config_detail);
-            let mut checker = context.create_checker();
-            checker.run_checks();
+            context.assert_violations(&violation, &resources);
+        }
 
-            let log_group_name = if violation == CloudWatchViolation::LogRetentionTooLong {
-                "MyLogGroup"
-            } else {
-                "MyLogGroup2"
-            };
-            let expected =
-                ExpectedViolations::new(vec![ExpectedViolation::new(&violation, log_group_name)]);
-            expected.assert_all_match(&context.error_reporter.render_errors());
+        fn tag_policy(match_: TagMatch) -> Option<RuleTypeConfigDetail> {
+            Some(RuleTypeConfigDetail::TagPolicy {
+                policy: TagPolicy {
+                    tags: vec![
+                        TagRequirement {
+                            key: "CostCenter".to_string(),
+                            pattern: None,
+                        },
+                        TagRequirement {
+                            key: "Environment".to_string(),
+                            pattern: Some(Regex::new("^(dev|prod)$").unwrap()),
+                        },
+                    ],
+                    match_,
+                },
+            })
         }
 
         #[rstest]
         #[case(
-            "cfn-testing.yaml",
-            RuleType::CW_003,
-            None,
-            CloudWatchViolation::InfrequentAccessLogGroupClass
+            "cfn-tagging.yaml",
+            RuleType::TAG_001,
+            tag_policy(TagMatch::All),
+            vec!["UntaggedFunction", "MyQueue", "MyBucket"]
         )]
-        fn test_cw_003(
+        #[case(
+            "cfn-tagging.yaml",
+            RuleType::TAG_001,
+            tag_policy(TagMatch::Any),
+            vec!["MyBucket"]
+        )]
+        #[case("cfn-tagging.yaml", RuleType::TAG_001, None, vec![])]
+        fn test_tagging(
             #[case] template_name: &str,
             #[case] rule_type: RuleType,
             #[case] config_detail: Option<RuleTypeConfigDetail>,
-            #[case] violation: CloudWatchViolation,
+            #[case] resources: Vec<&str>,
             setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
         ) {
             let mut context = setup_checker(template_name, rule_type, config_detail);
-            let mut checker = context.create_checker();
-            checker.run_checks();
+            context.assert_violations(&TaggingViolation::TagPolicy, &resources);
+        }
 
-            let expected = ExpectedViolations::new(vec![
-                ExpectedViolation::new(&violation, "MyLogGroup"),
-                ExpectedViolation::new(&violation, "MyLogGroup2"),
-            ]);
-            expected.assert_all_match(&context.error_reporter.render_errors());
+        #[rstest]
+        fn test_tagging_notes(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(
+                "cfn-tagging.yaml",
+                RuleType::TAG_001,
+                tag_policy(TagMatch::All),
+            );
+            context.create_checker().run_checks();
+
+            let rendered = context.error_reporter.render_errors();
+            assert!(rendered.contains("missing tags Environment"));
+            assert!(rendered.contains("tag Environment value \"test\" does not match ^(dev|prod)$"));
+            assert!(rendered.contains("missing tags CostCenter, Environment"));
+        }
+
+        #[rstest]
+        fn test_tagging_skips_functions_with_lambda_003(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(
+                "cfn-tagging.yaml",
+                RuleType::TAG_001,
+                tag_policy(TagMatch::All),
+            );
+            enable_rule(&mut context.config, RuleType::LAMBDA_003, None);
+            context.assert_violations(&TaggingViolation::TagPolicy, &["MyQueue", "MyBucket"]);
+        }
+
+        #[rstest]
+        fn test_tagging_stack_tags(
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context = setup_checker(
+                "cfn-tagging.yaml",
+                RuleType::TAG_001,
+                tag_policy(TagMatch::All),
+            );
+            let cloudformation = context.infra_template.cloudformation.as_mut().unwrap();
+            cloudformation
+                .stack_tags
+                .insert("CostCenter".to_string(), "1234".to_string());
+            cloudformation
+                .stack_tags
+                .insert("Environment".to_string(), "dev".to_string());
+
+            // Tags on the resource take precedence over stack tags
+            context.assert_violations(&TaggingViolation::TagPolicy, &["MyQueue"]);
+        }
+
+        #[rstest]
+        #[case(
+            Some(RuleTypeConfigDetail::Values {
+                values: vec![String::from("Environment")]
+            }),
+            vec!["UntaggedFunction"]
+        )]
+        #[case(tag_policy(TagMatch::All), vec!["UntaggedFunction"])]
+        #[case(
+            Some(RuleTypeConfigDetail::Values {
+                values: vec![String::from("Owner")]
+            }),
+            vec!["TaggedFunction", "UntaggedFunction", "LegacyFunction"]
+        )]
+        fn test_lambda_003_tag_policy(
+            #[case] config_detail: Option<RuleTypeConfigDetail>,
+            #[case] resources: Vec<&str>,
+            setup_checker: impl Fn(&str, RuleType, Option<RuleTypeConfigDetail>) -> TestContext,
+        ) {
+            let mut context =
+                setup_checker("cfn-tagging.yaml", RuleType::LAMBDA_003, config_detail);
+            context.assert_violations(&LambdaViolation::MissingTag, &resources);
         }
     }
 }
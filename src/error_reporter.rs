@@ -10,6 +10,8 @@ pub struct ErrorDetail {
     pub violation: Box<dyn Violation>,
     pub resource_name: String,
     pub span: Option<Span>,
+    /// Additional context rendered below the violation message
+    pub note: Option<String>,
 }
 
 impl ErrorDetail {
@@ -18,6 +20,7 @@ impl ErrorDetail {
             violation,
             resource_name,
             span,
+            note: None,
         }
     }
 }
@@ -40,6 +43,18 @@ impl ErrorReporter {
         self.errors.push(error_detail);
     }
 
+    pub fn add_error_with_note(
+        &mut self,
+        violation: Box<dyn Violation>,
+        resource_name: &str,
+        span: Option<Span>,
+        note: String,
+    ) {
+        let mut error_detail = ErrorDetail::new(violation, resource_name.to_string(), span);
+        error_detail.note = Some(note);
+        self.errors.push(error_detail);
+    }
+
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
@@ -57,11 +72,17 @@ impl ErrorReporter {
                 } else {
                     self.file_path.clone()
                 };
+                let note = e
+                    .note
+                    .as_ref()
+                    .map(|note| format!("note: {}\n", note))
+                    .unwrap_or_default();
                 format!(
-                    "{}:{}:{}\n{}\n",
+                    "{}:{}:{}\n{}{}\n",
                     e.violation.code(),
                     e.resource_name,
                     e.violation.message(),
+                    note,
                     span_info,
                 )
             })
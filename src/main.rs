@@ -8,16 +8,19 @@ mod error_reporter;
 mod parsers;
 mod rules;
 use crate::parsers::cfn::{parse_cloudformation, parse_samconfig};
+use crate::parsers::graph::GraphFormat;
 use crate::parsers::iac::InfratructureTemplate;
 use clap::Parser;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-    cloud_provider: String,
+    /// The cloud provider to check (`aws`), or `graph` to export the resource graph
+    #[arg(required_unless_present = "explain_config")]
+    cloud_provider: Option<String>,
 
-    #[arg(short, long)]
-    template: String,
+    #[arg(short, long, required_unless_present = "explain_config")]
+    template: Option<String>,
 
     #[arg(short, long, default_value = "default")]
     environment: String,
@@ -27,33 +30,81 @@ struct Args {
 
     #[arg(short, long, default_value_t = String::from("./cloudsaving.yaml"))]
     config: String,
-}
 
-fn main() -> ExitCode {
-    let args = Args::parse();
+    /// Output format of the `graph` command
+    #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+    format: GraphFormat,
+
+    /// Print the effective rule configuration of the environment and exit
+    #[arg(long)]
+    explain_config: bool,
+}
 
-    let cloud_provider = args.cloud_provider;
-    let template_file = args.template;
-    let config_file = args.config;
-    let config = Config::load(&config_file).unwrap_or_else(|e| {
+/// Loads the config, exiting when it is invalid or doesn't define `environment`.
+fn load_config(config_file: &str, environment: &str) -> Config {
+    let config = Config::load(config_file).unwrap_or_else(|e| {
         eprintln!("Failed to load config: {e}");
         std::process::exit(1);
     });
+    if let Some(cloudformation) = &config.cloudformation {
+        if let Err(e) = cloudformation.check_environment(environment) {
+            eprintln!("Invalid environment: {e}");
+            std::process::exit(1);
+        }
+    }
+    config
+}
+
+fn load_cloudformation(
+    template_file: &str,
+    samconfig: Option<&str>,
+    environment: &str,
+) -> InfratructureTemplate {
+    let mut parsed_cfn =
+        parse_cloudformation(template_file).expect("Failed to parse CloudFormation template");
+    if let Some(samconfig) = samconfig {
+        let samconfig = parse_samconfig(samconfig).expect("Failed to parse samconfig");
+        parsed_cfn.resolve_parameters(Some(&samconfig), environment);
+    } else {
+        parsed_cfn.resolve_parameters(None, environment);
+    }
+    InfratructureTemplate::new(Some(parsed_cfn))
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if args.explain_config {
+        let config = load_config(&args.config, &args.environment);
+        if let Some(cloudformation) = &config.cloudformation {
+            match cloudformation.explain(&args.environment) {
+                Ok(explained) => print!("{explained}"),
+                Err(e) => {
+                    eprintln!("Failed to render config: {e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let cloud_provider = args.cloud_provider.expect("cloud provider is required");
+    let template_file = args.template.expect("--template is required");
     let environment = args.environment;
+
+    if cloud_provider.as_str() == "graph" {
+        let infra_template =
+            load_cloudformation(&template_file, args.samconfig.as_deref(), &environment);
+        print!("{}", infra_template.graph.render(args.format));
+        return ExitCode::SUCCESS;
+    }
+
+    let config = load_config(&args.config, &environment);
     let mut error_reporter = error_reporter::ErrorReporter::new(&template_file);
 
     if cloud_provider.as_str() == "aws" {
-        let mut parsed_cfn =
-            parse_cloudformation(&template_file).expect("Failed to parse CloudFormation template");
-        if let Some(samconfig) = args.samconfig.as_deref() {
-            let samconfig = parse_samconfig(samconfig).expect("Failed to parse samconfig");
-            parsed_cfn.resolve_parameters(Some(&samconfig), environment.as_str());
-        } else {
-            parsed_cfn.resolve_parameters(None, environment.as_str());
-        }
-        let infra_template = InfratructureTemplate {
-            cloudformation: Some(parsed_cfn),
-        };
+        let infra_template =
+            load_cloudformation(&template_file, args.samconfig.as_deref(), &environment);
 
         let line_marker =
             parsers::get_yaml_line_marker(&template_file).expect("Failed to get YAML line marker");
@@ -20,6 +20,9 @@ pub(crate) struct CloudFormation {
     pub resources: Option<IndexMap<String, Resource>>,
     #[serde(rename = "Outputs")]
     pub outputs: Option<HashMap<String, Output>>,
+    /// Tags applied to every resource of the stack, from the samconfig `tags`
+    #[serde(skip)]
+    pub stack_tags: IndexMap<String, String>,
 }
 
 impl CloudFormation {
@@ -29,6 +32,18 @@ impl CloudFormation {
                 .environments
                 .get(environment)
                 .expect("Environment not found in samconfig");
+            if let Some(tags) = samconfig_section
+                .deploy
+                .as_ref()
+                .and_then(|deploy| deploy.parameters.as_ref())
+                .and_then(|parameters| parameters.tags.as_ref())
+            {
+                self.stack_tags = tags
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.replace("\"", "")))
+                    .collect();
+                self.stack_tags.sort_keys();
+            }
             // Check if the parameter is overridden in the samconfig
             if let Some(sam_deploy_parameters) = samconfig_section.deploy.as_ref() {
                 if let Some(parameters) = self.parameters.as_mut() {
@@ -181,28 +196,101 @@ impl CloudFormation {
                                 }
                             }
 
-                            // Apply architecture
-                            if let Some(architecture) =
-                                global_function_settings.get("Architectures")
+                            // Apply architecture and sizing
+                            for setting in
+                                ["Architectures", "MemorySize", "Timeout", "EphemeralStorage"]
                             {
-                                properties
-                                    .entry("Architectures".to_string())
-                                    .or_insert_with(|| architecture.clone());
+                                if let Some(value) = global_function_settings.get(setting) {
+                                    properties
+                                        .entry(setting.to_string())
+                                        .or_insert_with(|| value.clone());
+                                }
                             }
 
-                            // Apply event maximum retry attempts for serverless lambda function
-                            if let Some(event_invoke_config) =
-                                global_function_settings.get("EventInvokeConfig")
-                            {
+                            // Global tags are merged with the function's own tags
+                            if let Some(global_tags) = global_function_settings.get("Tags") {
                                 if let AWSResourceType::LambdaServerlessFunction = &resource.type_ {
-                                    properties
-                                        .entry("EventInvokeConfig".to_string())
-                                        .or_insert_with(|| event_invoke_config.clone());
+                                    match properties.get_mut("Tags") {
+                                        Some(tags) => merge_global_value(tags, global_tags),
+                                        None => {
+                                            properties
+                                                .insert("Tags".to_string(), global_tags.clone());
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Apply event maximum retry attempts and provisioned concurrency
+                            // for serverless lambda function
+                            for setting in [
+                                "EventInvokeConfig",
+                                "AutoPublishAlias",
+                                "ProvisionedConcurrencyConfig",
+                            ] {
+                                if let Some(value) = global_function_settings.get(setting) {
+                                    if let AWSResourceType::LambdaServerlessFunction =
+                                        &resource.type_
+                                    {
+                                        properties
+                                            .entry(setting.to_string())
+                                            .or_insert_with(|| value.clone());
+                                    }
                                 }
                             }
                         }
                     }
                 }
+
+                // Apply Globals.Api, Globals.HttpApi and Globals.StateMachine to serverless resources
+                let global_settings = match &resource.type_ {
+                    AWSResourceType::ServerlessApi => {
+                        self.globals.as_ref().and_then(|g| g.api.as_ref())
+                    }
+                    AWSResourceType::ServerlessHttpApi => {
+                        self.globals.as_ref().and_then(|g| g.http_api.as_ref())
+                    }
+                    AWSResourceType::ServerlessStateMachine => {
+                        self.globals.as_ref().and_then(|g| g.state_machine.as_ref())
+                    }
+                    _ => None,
+                };
+                if let Some(global_settings) = global_settings {
+                    merge_global_properties(
+                        resource.properties.get_or_insert_with(HashMap::new),
+                        global_settings,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Merges SAM `Globals` settings into a resource's properties. Values defined on the
+/// resource take precedence, and nested mappings are merged key by key.
+fn merge_global_properties(
+    properties: &mut HashMap<String, serde_yaml::Value>,
+    globals: &HashMap<String, serde_yaml::Value>,
+) {
+    for (key, global_value) in globals {
+        match properties.get_mut(key) {
+            Some(value) => merge_global_value(value, global_value),
+            None => {
+                properties.insert(key.clone(), global_value.clone());
+            }
+        }
+    }
+}
+
+fn merge_global_value(value: &mut serde_yaml::Value, global_value: &serde_yaml::Value) {
+    if let (serde_yaml::Value::Mapping(mapping), serde_yaml::Value::Mapping(global_mapping)) =
+        (value, global_value)
+    {
+        for (key, global_value) in global_mapping {
+            match mapping.get_mut(key) {
+                Some(value) => merge_global_value(value, global_value),
+                None => {
+                    mapping.insert(key.clone(), global_value.clone());
+                }
             }
         }
     }
@@ -296,12 +384,114 @@ pub struct Resource {
     pub other: HashMap<String, serde_yaml::Value>, // Capture additional resource attributes if needed
 }
 
+impl Resource {
+    /// Returns the SAM event sources declared under `Events` as `(name, type, properties)`.
+    pub fn events(&self) -> Vec<(&str, &str, Option<&serde_yaml::Value>)> {
+        self.properties
+            .as_ref()
+            .and_then(|props| props.get("Events"))
+            .and_then(|events| events.as_mapping())
+            .map(|events| {
+                events
+                    .iter()
+                    .filter_map(|(name, event)| {
+                        Some((
+                            name.as_str()?,
+                            event.get("Type")?.as_str()?,
+                            event.get("Properties"),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
 impl IaCResource for Resource {
     fn get_type(&self) -> String {
         match &self.type_ {
             AWSResourceType::LambdaFunction => "AWS::Lambda::Function".to_string(),
             AWSResourceType::LambdaServerlessFunction => "AWS::Serverless::Function".to_string(),
+            AWSResourceType::LambdaAlias => "AWS::Lambda::Alias".to_string(),
+            AWSResourceType::LambdaVersion => "AWS::Lambda::Version".to_string(),
+            AWSResourceType::LambdaEventSourceMapping => {
+                "AWS::Lambda::EventSourceMapping".to_string()
+            }
             AWSResourceType::CloudWatch => "AWS::Logs::LogGroup".to_string(),
+            AWSResourceType::CloudWatchAlarm => "AWS::CloudWatch::Alarm".to_string(),
+            AWSResourceType::CloudWatchDashboard => "AWS::CloudWatch::Dashboard".to_string(),
+            AWSResourceType::LogsMetricFilter => "AWS::Logs::MetricFilter".to_string(),
+            AWSResourceType::LogsSubscriptionFilter => "AWS::Logs::SubscriptionFilter".to_string(),
+            AWSResourceType::EcsTaskDefinition => "AWS::ECS::TaskDefinition".to_string(),
+            AWSResourceType::EcsService => "AWS::ECS::Service".to_string(),
+            AWSResourceType::EcsCluster => "AWS::ECS::Cluster".to_string(),
+            AWSResourceType::ServerlessApi => "AWS::Serverless::Api".to_string(),
+            AWSResourceType::ServerlessHttpApi => "AWS::Serverless::HttpApi".to_string(),
+            AWSResourceType::StepFunctionsStateMachine => {
+                "AWS::StepFunctions::StateMachine".to_string()
+            }
+            AWSResourceType::ServerlessStateMachine => "AWS::Serverless::StateMachine".to_string(),
+            AWSResourceType::KinesisStream => "AWS::Kinesis::Stream".to_string(),
+            AWSResourceType::KinesisFirehoseDeliveryStream => {
+                "AWS::KinesisFirehose::DeliveryStream".to_string()
+            }
+            AWSResourceType::MskCluster => "AWS::MSK::Cluster".to_string(),
+            AWSResourceType::SqsQueue => "AWS::SQS::Queue".to_string(),
+            AWSResourceType::SnsTopic => "AWS::SNS::Topic".to_string(),
+            AWSResourceType::SnsSubscription => "AWS::SNS::Subscription".to_string(),
+            AWSResourceType::EventsRule => "AWS::Events::Rule".to_string(),
+            AWSResourceType::EventsArchive => "AWS::Events::Archive".to_string(),
+            AWSResourceType::SchedulerSchedule => "AWS::Scheduler::Schedule".to_string(),
+            AWSResourceType::ElastiCacheCacheCluster => {
+                "AWS::ElastiCache::CacheCluster".to_string()
+            }
+            AWSResourceType::ElastiCacheReplicationGroup => {
+                "AWS::ElastiCache::ReplicationGroup".to_string()
+            }
+            AWSResourceType::ElastiCacheServerlessCache => {
+                "AWS::ElastiCache::ServerlessCache".to_string()
+            }
+            AWSResourceType::OpenSearchDomain => "AWS::OpenSearchService::Domain".to_string(),
+            AWSResourceType::CloudFrontDistribution => "AWS::CloudFront::Distribution".to_string(),
+            AWSResourceType::S3Bucket => "AWS::S3::Bucket".to_string(),
+            AWSResourceType::EcrRepository => "AWS::ECR::Repository".to_string(),
+            AWSResourceType::EfsFileSystem => "AWS::EFS::FileSystem".to_string(),
+            AWSResourceType::EfsAccessPoint => "AWS::EFS::AccessPoint".to_string(),
+            AWSResourceType::FsxFileSystem => "AWS::FSx::FileSystem".to_string(),
+            AWSResourceType::SecretsManagerSecret => "AWS::SecretsManager::Secret".to_string(),
+            AWSResourceType::SecretsManagerRotationSchedule => {
+                "AWS::SecretsManager::RotationSchedule".to_string()
+            }
+            AWSResourceType::KmsKey => "AWS::KMS::Key".to_string(),
+            AWSResourceType::KmsAlias => "AWS::KMS::Alias".to_string(),
+            AWSResourceType::SsmParameter => "AWS::SSM::Parameter".to_string(),
+            AWSResourceType::GlueJob => "AWS::Glue::Job".to_string(),
+            AWSResourceType::GlueCrawler => "AWS::Glue::Crawler".to_string(),
+            AWSResourceType::AthenaWorkGroup => "AWS::Athena::WorkGroup".to_string(),
+            AWSResourceType::SageMakerEndpoint => "AWS::SageMaker::Endpoint".to_string(),
+            AWSResourceType::SageMakerEndpointConfig => {
+                "AWS::SageMaker::EndpointConfig".to_string()
+            }
+            AWSResourceType::SageMakerNotebookInstance => {
+                "AWS::SageMaker::NotebookInstance".to_string()
+            }
+            AWSResourceType::SageMakerDomain => "AWS::SageMaker::Domain".to_string(),
+            AWSResourceType::ApplicationAutoScalingScalableTarget => {
+                "AWS::ApplicationAutoScaling::ScalableTarget".to_string()
+            }
+            AWSResourceType::BackupPlan => "AWS::Backup::BackupPlan".to_string(),
+            AWSResourceType::BackupVault => "AWS::Backup::BackupVault".to_string(),
+            AWSResourceType::RdsDbInstance => "AWS::RDS::DBInstance".to_string(),
+            AWSResourceType::RdsDbCluster => "AWS::RDS::DBCluster".to_string(),
+            AWSResourceType::Ec2Volume => "AWS::EC2::Volume".to_string(),
+            AWSResourceType::CodeBuildProject => "AWS::CodeBuild::Project".to_string(),
+            AWSResourceType::CodePipelinePipeline => "AWS::CodePipeline::Pipeline".to_string(),
+            AWSResourceType::RedshiftCluster => "AWS::Redshift::Cluster".to_string(),
+            AWSResourceType::RedshiftServerlessWorkgroup => {
+                "AWS::RedshiftServerless::Workgroup".to_string()
+            }
+            AWSResourceType::DocDbCluster => "AWS::DocDB::DBCluster".to_string(),
+            AWSResourceType::DocDbInstance => "AWS::DocDB::DBInstance".to_string(),
             AWSResourceType::Unknown(t) => t.clone(),
         }
     }
@@ -345,6 +535,96 @@ impl<'de> Deserialize<'de> for Resource {
         })
     }
 }
+
+/// Returns the logical ID targeted by a `!Ref` or `Ref:` intrinsic function.
+pub(crate) fn get_ref(value: &serde_yaml::Value) -> Option<&str> {
+    match value {
+        serde_yaml::Value::Tagged(tagged_value) if tagged_value.tag == "!Ref" => {
+            tagged_value.value.as_str()
+        }
+        serde_yaml::Value::Mapping(mapping) => mapping.get("Ref").and_then(|v| v.as_str()),
+        _ => None,
+    }
+}
+
+/// Returns the logical ID and attribute targeted by a `!GetAtt` or `Fn::GetAtt:` intrinsic function.
+pub(crate) fn get_att(value: &serde_yaml::Value) -> Option<(&str, &str)> {
+    let target = match value {
+        serde_yaml::Value::Tagged(tagged_value) if tagged_value.tag == "!GetAtt" => {
+            &tagged_value.value
+        }
+        serde_yaml::Value::Mapping(mapping) => mapping.get("Fn::GetAtt")?,
+        _ => return None,
+    };
+    match target {
+        serde_yaml::Value::String(s) => s.split_once('.'),
+        serde_yaml::Value::Sequence(seq) => Some((seq.first()?.as_str()?, seq.get(1)?.as_str()?)),
+        _ => None,
+    }
+}
+
+/// Returns the logical ID referenced by either `!Ref` or `!GetAtt`.
+pub(crate) fn get_ref_or_att(value: &serde_yaml::Value) -> Option<&str> {
+    get_ref(value).or_else(|| get_att(value).map(|(logical_id, _)| logical_id))
+}
+
+/// Renders a string expression as a `Fn::Sub` template, so that equivalent `!Sub`, `!Join`,
+/// `!Ref` and literal values compare equal. `!Ref MyFunction` renders as `${MyFunction}`.
+pub(crate) fn get_sub_template(value: &serde_yaml::Value) -> Option<String> {
+    if let Some(logical_id) = get_ref(value) {
+        return Some(format!("${{{}}}", logical_id));
+    }
+    if let Some((logical_id, attribute)) = get_att(value) {
+        return Some(format!("${{{}.{}}}", logical_id, attribute));
+    }
+    let (function, args) = match value {
+        serde_yaml::Value::String(s) => return Some(s.clone()),
+        serde_yaml::Value::Tagged(tagged_value) => (
+            tagged_value
+                .tag
+                .to_string()
+                .trim_start_matches('!')
+                .to_string(),
+            &tagged_value.value,
+        ),
+        serde_yaml::Value::Mapping(mapping) if mapping.len() == 1 => {
+            let (key, args) = mapping.iter().next()?;
+            (key.as_str()?.trim_start_matches("Fn::").to_string(), args)
+        }
+        _ => return None,
+    };
+    match function.as_str() {
+        "Sub" => match args {
+            serde_yaml::Value::String(template) => Some(template.clone()),
+            serde_yaml::Value::Sequence(seq) => {
+                let mut template = seq.first()?.as_str()?.to_string();
+                if let Some(variables) = seq.get(1).and_then(|v| v.as_mapping()) {
+                    for (name, variable) in variables {
+                        template = template.replace(
+                            &format!("${{{}}}", name.as_str()?),
+                            &get_sub_template(variable)?,
+                        );
+                    }
+                }
+                Some(template)
+            }
+            _ => None,
+        },
+        "Join" => {
+            let seq = args.as_sequence()?;
+            let delimiter = seq.first()?.as_str()?;
+            let parts = seq
+                .get(1)?
+                .as_sequence()?
+                .iter()
+                .map(get_sub_template)
+                .collect::<Option<Vec<String>>>()?;
+            Some(parts.join(delimiter))
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn parse_cloudformation(
     file_path: &str,
 ) -> Result<CloudFormation, Box<dyn std::error::Error>> {
@@ -381,6 +661,8 @@ pub struct DeployParameterValues {
     pub capabilities: Option<String>,
     #[serde(deserialize_with = "deserialize_parameter_overrides")]
     pub parameter_overrides: Option<HashMap<String, String>>,
+    #[serde(default, deserialize_with = "deserialize_parameter_overrides")]
+    pub tags: Option<HashMap<String, String>>,
     pub image_repositories: Option<Vec<String>>,
 }
 
@@ -452,6 +734,21 @@ mod test {
         assert_eq!(cloudformation.outputs.unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_parse_resource_types() {
+        let cloudformation = parse_cloudformation("src/fixtures/aws/cfn-elasticache.yaml").unwrap();
+        let resources = cloudformation.resources.unwrap();
+        let serverless_cache = &resources["MyServerlessCache"];
+        assert!(matches!(
+            serverless_cache.type_,
+            AWSResourceType::ElastiCacheServerlessCache
+        ));
+        assert_eq!(
+            serverless_cache.get_type(),
+            "AWS::ElastiCache::ServerlessCache"
+        );
+    }
+
     #[test]
     fn test_resolve_parameters() {
         let mut cloudformation =
@@ -503,6 +800,9 @@ mod test {
         // Check if the global architecture is applied to the resources
         let architecture = properties.get("Architectures").unwrap();
         assert_eq!(architecture, &serde_yaml::Value::from(vec!["arm64"]));
+        // Check if the global timeout is applied to the resources
+        let timeout = properties.get("Timeout").unwrap();
+        assert_eq!(timeout, &serde_yaml::Value::from(60));
         // Check if the global event invoke config is applied to the resources
         let event_invoke_config = properties.get("EventInvokeConfig").unwrap();
         let mut expected_mapping = serde_yaml::Mapping::new();
@@ -516,6 +816,64 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_resolve_tags() {
+        let mut cloudformation = parse_cloudformation("src/fixtures/aws/cfn-tagging.yaml").unwrap();
+        let samconfig = parse_samconfig("src/fixtures/aws/samconfig.toml").unwrap();
+        cloudformation.resolve_parameters(Some(&samconfig), "prod");
+        assert_eq!(
+            cloudformation.stack_tags,
+            IndexMap::from([
+                ("CostCenter".to_string(), "9999".to_string()),
+                ("Owner".to_string(), "platform".to_string()),
+            ])
+        );
+
+        // Global tags are merged into the function's tags
+        let resources = cloudformation.resources.as_ref().unwrap();
+        let tags = |key: &str| {
+            resources
+                .get(key)
+                .unwrap()
+                .properties
+                .as_ref()
+                .unwrap()
+                .get("Tags")
+                .cloned()
+        };
+        let expected: serde_yaml::Value =
+            serde_yaml::from_str("{Environment: prod, CostCenter: \"1234\"}").unwrap();
+        assert_eq!(tags("TaggedFunction"), Some(expected));
+        let expected: serde_yaml::Value = serde_yaml::from_str("{CostCenter: \"1234\"}").unwrap();
+        assert_eq!(tags("UntaggedFunction"), Some(expected));
+        // Globals only apply to SAM resources
+        assert!(tags("LegacyFunction").unwrap().is_sequence());
+
+        // Environments without `tags` have no stack tags
+        let mut cloudformation = parse_cloudformation("src/fixtures/aws/cfn-tagging.yaml").unwrap();
+        cloudformation.resolve_parameters(Some(&samconfig), "default");
+        assert!(cloudformation.stack_tags.is_empty());
+    }
+
+    #[test]
+    fn test_get_sub_template() {
+        let expressions: Vec<serde_yaml::Value> = serde_yaml::from_str(
+            r#"
+            - "/aws/lambda/my-function"
+            - !Sub "/aws/lambda/${MyFunction}"
+            - !Sub ["/aws/lambda/${Name}", { Name: !Ref MyFunction }]
+            - !Join ["", ["/aws/lambda/", !Ref MyFunction]]
+            - { "Fn::Join": ["/", ["", "aws", "lambda", { "Ref": "MyFunction" }]] }
+            "#,
+        )
+        .unwrap();
+        let templates: Vec<Option<String>> = expressions.iter().map(get_sub_template).collect();
+        assert_eq!(templates[0], Some("/aws/lambda/my-function".to_string()));
+        for template in &templates[1..] {
+            assert_eq!(template, &Some("/aws/lambda/${MyFunction}".to_string()));
+        }
+    }
+
     #[test]
     fn test_parse_samconfig() {
         let samconfig = parse_samconfig("src/fixtures/aws/samconfig.toml").unwrap();
@@ -1,5 +1,9 @@
+use crate::parsers::node_types::NodeTypes;
+use regex::Regex;
+use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::fs;
 use std::hash::Hash;
 
@@ -11,19 +15,131 @@ pub struct RuleTypeConfig {
 }
 
 #[derive(Debug, Serialize, PartialEq, Clone)]
+#[serde(untagged)]
 pub enum ThresholdValue {
     Int(u64),
     Float(f64),
 }
 
-#[derive(Debug, Serialize, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum RuleTypeConfigDetail {
     Value { value: String },
     Values { values: Vec<String> },
     Threshold { threshold: ThresholdValue },
+    TagPolicy { policy: TagPolicy },
     Simple,
 }
 
+/// Tags a resource is expected to carry.
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct TagPolicy {
+    pub tags: Vec<TagRequirement>,
+    #[serde(rename = "match")]
+    pub match_: TagMatch,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TagRequirement {
+    pub key: String,
+    /// Regular expression the tag value must match, compiled when the config is loaded
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_pattern"
+    )]
+    pub pattern: Option<Regex>,
+}
+
+// `Regex` implements neither `PartialEq` nor `Serialize`, so patterns are compared and
+// written as their source
+impl PartialEq for TagRequirement {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.pattern.as_ref().map(Regex::as_str) == other.pattern.as_ref().map(Regex::as_str)
+    }
+}
+
+fn serialize_pattern<S>(pattern: &Option<Regex>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    pattern.as_ref().map(Regex::as_str).serialize(serializer)
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatch {
+    /// Every tag must be present
+    #[default]
+    All,
+    /// At least one of the tags must be present
+    Any,
+}
+
+impl TagPolicy {
+    /// Builds a policy requiring tag keys with any value.
+    pub fn from_keys(keys: &[String], match_: TagMatch) -> Self {
+        Self {
+            tags: keys
+                .iter()
+                .map(|key| TagRequirement {
+                    key: key.clone(),
+                    pattern: None,
+                })
+                .collect(),
+            match_,
+        }
+    }
+
+    fn from_yaml(
+        tags: &serde_yaml::Value,
+        match_: Option<&serde_yaml::Value>,
+    ) -> Result<Self, String> {
+        let tags = tags
+            .as_sequence()
+            .ok_or("`tags` must be a list")?
+            .iter()
+            .map(|tag| match tag {
+                // A plain key requires the tag with any value
+                serde_yaml::Value::String(key) => Ok(TagRequirement {
+                    key: key.clone(),
+                    pattern: None,
+                }),
+                serde_yaml::Value::Mapping(_) => {
+                    let key = tag
+                        .get("key")
+                        .and_then(|key| key.as_str())
+                        .ok_or("each tag requires a `key`")?;
+                    let pattern = match tag.get("pattern") {
+                        Some(pattern) => {
+                            let pattern = pattern
+                                .as_str()
+                                .ok_or(format!("the pattern of tag `{key}` must be a string"))?;
+                            Some(
+                                Regex::new(pattern)
+                                    .map_err(|e| format!("invalid pattern for tag `{key}`: {e}"))?,
+                            )
+                        }
+                        None => None,
+                    };
+                    Ok(TagRequirement {
+                        key: key.to_string(),
+                        pattern,
+                    })
+                }
+                _ => {
+                    Err("each tag must be a key or a mapping with `key` and `pattern`".to_string())
+                }
+            })
+            .collect::<Result<_, String>>()?;
+        let match_ = match match_ {
+            Some(match_) => serde_yaml::from_value(match_.clone())
+                .map_err(|_| "`match` must be `all` or `any`")?,
+            None => TagMatch::default(),
+        };
+        Ok(Self { tags, match_ })
+    }
+}
+
 impl<'de> Deserialize<'de> for RuleTypeConfigDetail {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -31,7 +147,11 @@ impl<'de> Deserialize<'de> for RuleTypeConfigDetail {
     {
         let map = HashMap::<String, serde_yaml::Value>::deserialize(deserializer)?;
 
-        if let Some(values) = map.get("values") {
+        if let Some(tags) = map.get("tags") {
+            return TagPolicy::from_yaml(tags, map.get("match"))
+                .map(|policy| RuleTypeConfigDetail::TagPolicy { policy })
+                .map_err(serde::de::Error::custom);
+        } else if let Some(values) = map.get("values") {
             if let Some(values) = values.as_sequence() {
                 let values = values
                     .iter()
@@ -61,6 +181,29 @@ impl<'de> Deserialize<'de> for RuleTypeConfigDetail {
     }
 }
 
+impl Serialize for RuleTypeConfigDetail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Written with the keys `deserialize` reads, so it can be copied into a config file
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            RuleTypeConfigDetail::Value { value } => map.serialize_entry("value", value)?,
+            RuleTypeConfigDetail::Values { values } => map.serialize_entry("values", values)?,
+            RuleTypeConfigDetail::Threshold { threshold } => {
+                map.serialize_entry("threshold", threshold)?
+            }
+            RuleTypeConfigDetail::TagPolicy { policy } => {
+                map.serialize_entry("match", &policy.match_)?;
+                map.serialize_entry("tags", &policy.tags)?;
+            }
+            RuleTypeConfigDetail::Simple => {}
+        }
+        map.end()
+    }
+}
+
 impl RuleTypeConfigDetail {
     pub fn get_value(&self) -> Option<&String> {
         if let RuleTypeConfigDetail::Value { value } = self {
@@ -78,6 +221,14 @@ impl RuleTypeConfigDetail {
         }
     }
 
+    pub fn get_tag_policy(&self) -> Option<&TagPolicy> {
+        if let RuleTypeConfigDetail::TagPolicy { policy } = self {
+            Some(policy)
+        } else {
+            None
+        }
+    }
+
     pub fn get_threshold_int(&self) -> Option<u64> {
         if let RuleTypeConfigDetail::Threshold {
             threshold: ThresholdValue::Int(value),
@@ -88,18 +239,21 @@ impl RuleTypeConfigDetail {
         None
     }
 
-    pub fn get_threshold_float(&self) -> Option<f64> {
-        if let RuleTypeConfigDetail::Threshold {
-            threshold: ThresholdValue::Float(value),
-        } = self
-        {
-            return Some(*value);
+    /// Returns the threshold as a float regardless of whether it was written as an integer.
+    pub fn get_threshold_number(&self) -> Option<f64> {
+        match self {
+            RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Int(value),
+            } => Some(*value as f64),
+            RuleTypeConfigDetail::Threshold {
+                threshold: ThresholdValue::Float(value),
+            } => Some(*value),
+            _ => None,
         }
-        None
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
 #[allow(non_camel_case_types)]
 pub enum RuleType {
     LAMBDA_001,
@@ -109,35 +263,319 @@ pub enum RuleType {
     LAMBDA_005,
     LAMBDA_006,
     LAMBDA_007,
+    LAMBDA_008,
+    LAMBDA_009,
+    LAMBDA_010,
+    LAMBDA_011,
+    LAMBDA_012,
+    LAMBDA_013,
+    LAMBDA_014,
+    LAMBDA_015,
     CW_001,
     CW_002,
     CW_003,
+    CW_004,
+    CW_005,
+    CW_006,
+    CW_007,
+    CW_008,
+    ECS_001,
+    ECS_002,
+    ECS_003,
+    ECS_004,
+    ECS_005,
+    APIGW_001,
+    APIGW_002,
+    APIGW_003,
+    APIGW_004,
+    SFN_001,
+    SFN_002,
+    SFN_003,
+    STREAM_001,
+    STREAM_002,
+    STREAM_003,
+    STREAM_004,
+    STREAM_005,
+    MSG_001,
+    MSG_002,
+    MSG_003,
+    MSG_004,
+    MSG_005,
+    ELASTICACHE_001,
+    ELASTICACHE_002,
+    ELASTICACHE_003,
+    OPENSEARCH_001,
+    OPENSEARCH_002,
+    OPENSEARCH_003,
+    OPENSEARCH_004,
+    OPENSEARCH_005,
+    CLOUDFRONT_001,
+    CLOUDFRONT_002,
+    CLOUDFRONT_003,
+    CLOUDFRONT_004,
+    CLOUDFRONT_005,
+    ECR_001,
+    ECR_002,
+    FS_001,
+    FS_002,
+    FS_003,
+    FS_004,
+    SECRET_001,
+    SECRET_002,
+    SECRET_003,
+    SECRET_004,
+    SECRET_005,
+    GLUE_001,
+    GLUE_002,
+    GLUE_003,
+    GLUE_004,
+    ATHENA_001,
+    ATHENA_002,
+    SAGEMAKER_001,
+    SAGEMAKER_002,
+    SAGEMAKER_003,
+    SAGEMAKER_004,
+    SCHEDULE_001,
+    BACKUP_001,
+    BACKUP_002,
+    BACKUP_003,
+    BACKUP_004,
+    CODEBUILD_001,
+    CODEBUILD_002,
+    CODEBUILD_003,
+    CODEBUILD_004,
+    CODEPIPELINE_001,
+    CODEPIPELINE_002,
+    REDSHIFT_001,
+    REDSHIFT_002,
+    REDSHIFT_003,
+    DOCDB_001,
+    DOCDB_002,
+    TAG_001,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RuleConfig {
     pub rules: HashMap<RuleType, RuleTypeConfig>,
-    pub environments: HashMap<String, Option<HashMap<RuleType, RuleTypeConfig>>>,
+    pub environments: HashMap<String, Option<Environment>>,
+    /// Path to node type tables replacing the built-in ones
+    #[serde(default, rename = "node_types")]
+    pub node_types_file: Option<String>,
+    #[serde(skip)]
+    pub node_types: NodeTypes,
+}
+
+/// The rules of an environment. `Config::load` replaces the overrides read from the file
+/// with the effective rules and records where each one came from.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct Environment {
+    /// Environment whose effective rules are overridden, instead of the top-level `rules`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    #[serde(flatten)]
+    pub rules: HashMap<RuleType, RuleTypeConfig>,
+    #[serde(skip)]
+    pub sources: HashMap<RuleType, RuleSource>,
+}
+
+/// Where the configuration of a rule is defined.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RuleSource {
+    /// The built-in default
+    Default,
+    /// The top-level `rules`
+    Rules,
+    /// The overrides of an environment
+    Environment(String),
+}
+
+impl fmt::Display for RuleSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleSource::Default => write!(f, "default"),
+            RuleSource::Rules => write!(f, "rules"),
+            RuleSource::Environment(name) => write!(f, "environments.{name}"),
+        }
+    }
+}
+
+impl Serialize for RuleSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Serialize)]
+struct ExplainedEnvironment<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extends: Option<&'a str>,
+    #[serde(flatten)]
+    rules: BTreeMap<RuleType, ExplainedRule<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExplainedRule<'a> {
+    #[serde(flatten)]
+    config: &'a RuleTypeConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<&'a RuleSource>,
+}
+
+/// Resolves an environment after the environments it extends. `chain` holds the environments
+/// being resolved, to detect cycles.
+fn resolve_environment(
+    name: &str,
+    environments: &HashMap<String, Option<Environment>>,
+    base: &Environment,
+    chain: &mut Vec<String>,
+    resolved: &mut HashMap<String, Environment>,
+) -> Result<Environment, String> {
+    if let Some(environment) = resolved.get(name) {
+        return Ok(environment.clone());
+    }
+    if let Some(start) = chain.iter().position(|env| env == name) {
+        let mut cycle = chain[start..].to_vec();
+        cycle.push(name.to_string());
+        return Err(format!(
+            "environments extend each other in a cycle: {}",
+            cycle.join(" -> ")
+        ));
+    }
+
+    let overrides = environments
+        .get(name)
+        .cloned()
+        .flatten()
+        .unwrap_or_default();
+    let mut effective = match &overrides.extends {
+        Some(parent) => {
+            if !environments.contains_key(parent) {
+                return Err(format!(
+                    "environment `{name}` extends `{parent}`, which is not defined"
+                ));
+            }
+            chain.push(name.to_string());
+            let parent = resolve_environment(parent, environments, base, chain, resolved)?;
+            chain.pop();
+            parent
+        }
+        None => base.clone(),
+    };
+    effective.extends = overrides.extends;
+    for (rule, config) in overrides.rules {
+        effective
+            .sources
+            .insert(rule.clone(), RuleSource::Environment(name.to_string()));
+        effective.rules.insert(rule, config);
+    }
+
+    resolved.insert(name.to_string(), effective.clone());
+    Ok(effective)
 }
 
 impl RuleConfig {
-    pub fn enabled(&self, violation: RuleType, environment: &str) -> bool {
-        if let Some(rule) = self
+    /// Renders the effective rules of `environment` as YAML, noting where each rule's
+    /// configuration came from.
+    pub fn explain(&self, environment: &str) -> Result<String, serde_yaml::Error> {
+        let resolved = self
             .environments
             .get(environment)
-            .and_then(|env| env.as_ref())
-            .and_then(|rules| rules.get(&violation))
-        {
-            return rule.enabled;
+            .and_then(|env| env.as_ref());
+        let explained = ExplainedEnvironment {
+            extends: resolved.and_then(|env| env.extends.as_deref()),
+            rules: self
+                .context(environment)
+                .rules()
+                .into_iter()
+                .map(|(rule, config)| {
+                    let source = resolved.and_then(|env| env.sources.get(&rule));
+                    (rule, ExplainedRule { config, source })
+                })
+                .collect(),
+        };
+        serde_yaml::to_string(&BTreeMap::from([(environment, explained)]))
+    }
+
+    /// Returns an error listing the defined environments if `environment` is not one of them.
+    pub fn check_environment(&self, environment: &str) -> Result<(), String> {
+        if self.environments.contains_key(environment) {
+            return Ok(());
         }
-        false
+        let mut defined: Vec<&str> = self.environments.keys().map(String::as_str).collect();
+        defined.sort();
+        Err(format!(
+            "environment `{environment}` is not defined, expected one of: {}",
+            defined.join(", ")
+        ))
     }
 
-    pub fn get_rule(&self, rule: RuleType, environment: &str) -> Option<&RuleTypeConfig> {
-        self.environments
-            .get(environment)
-            .and_then(|rules| rules.as_ref())
-            .and_then(|rules| rules.get(&rule))
+    /// Replaces the overrides of each environment with its effective rules.
+    fn resolve_environments(
+        &mut self,
+        sources: HashMap<RuleType, RuleSource>,
+    ) -> Result<(), String> {
+        let base = Environment {
+            extends: None,
+            rules: self.rules.clone(),
+            sources,
+        };
+        let mut resolved = HashMap::new();
+        for name in self.environments.keys() {
+            resolve_environment(name, &self.environments, &base, &mut vec![], &mut resolved)?;
+        }
+        self.environments = resolved
+            .into_iter()
+            .map(|(name, environment)| (name, Some(environment)))
+            .collect();
+        Ok(())
+    }
+
+    /// Returns the rules as configured for `environment`.
+    pub fn context<'a>(&'a self, environment: &'a str) -> RuleContext<'a> {
+        RuleContext {
+            config: self,
+            environment,
+        }
+    }
+}
+
+/// The effective rule configuration of one environment. Rules read their configuration
+/// through it so environment overrides are always applied.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleContext<'a> {
+    config: &'a RuleConfig,
+    environment: &'a str,
+}
+
+impl<'a> RuleContext<'a> {
+    /// Returns the environment's configuration of the rule. Environments that aren't defined
+    /// have no rules.
+    pub fn get(&self, rule: RuleType) -> Option<&'a RuleTypeConfig> {
+        self.config
+            .environments
+            .get(self.environment)
+            .and_then(|environment| environment.as_ref())
+            .and_then(|environment| environment.rules.get(&rule))
+    }
+
+    pub fn enabled(&self, rule: RuleType) -> bool {
+        self.get(rule).is_some_and(|rule| rule.enabled)
+    }
+
+    /// Returns the configuration of every rule, ordered by rule type.
+    pub fn rules(&self) -> BTreeMap<RuleType, &'a RuleTypeConfig> {
+        self.config
+            .rules
+            .keys()
+            .filter_map(|rule| Some((rule.clone(), self.get(rule.clone())?)))
+            .collect()
+    }
+
+    pub fn node_types(&self) -> &'a NodeTypes {
+        &self.config.node_types
     }
 }
 
@@ -201,6 +639,72 @@ impl Default for RuleConfig {
                 },
             },
         );
+        rules.insert(
+            RuleType::LAMBDA_008,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(1024),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_009,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(600),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_010,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(512),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_011,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_012,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(10),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_013,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_014,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::LAMBDA_015,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(10),
+                },
+            },
+        );
         rules.insert(
             RuleType::CW_001,
             RuleTypeConfig {
@@ -224,142 +728,804 @@ impl Default for RuleConfig {
                 config_detail: RuleTypeConfigDetail::Simple,
             },
         );
-        let mut environments = HashMap::new();
-        environments.insert("default".to_string(), Some(rules.clone()));
-
-        RuleConfig {
-            rules,
-            environments,
-        }
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Config {
-    #[serde(default)]
-    pub cloudformation: Option<RuleConfig>,
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Config {
-            cloudformation: Some(RuleConfig::default()),
-        }
-    }
-}
-
-impl Config {
-    pub fn load(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let data = fs::read_to_string(file_path)?;
-        let mut config: Config = serde_yaml::from_str(&data)?;
-
-        // Merge default rules with the loaded configuration
-        let default_rules: HashMap<RuleType, RuleTypeConfig> = RuleConfig::default().rules;
-        if let Some(ref mut cloudformation) = config.cloudformation {
-            for (rule_name, default_rule) in default_rules {
-                cloudformation
-                    .rules
-                    .entry(rule_name)
-                    .or_insert(default_rule);
-            }
-        }
-
-        if let Some(ref mut cloudformation) = config.cloudformation {
-            // Create `default` environment
-            cloudformation
-                .environments
-                .entry("default".to_string())
-                .or_insert_with(|| Some(cloudformation.rules.clone()));
-            for rules in cloudformation.environments.values_mut() {
-                // No override rules, apply default rules
-                if rules.is_none() {
-                    rules.replace(cloudformation.rules.clone());
-                } else {
-                    // Merge default rules with the loaded configuration
-                    if let Some(rules) = rules {
-                        for (rule_name, default_rule) in &cloudformation.rules.clone() {
-                            rules
-                                .entry(rule_name.clone())
-                                .or_insert_with(|| default_rule.clone());
-                        }
-                    }
-                }
-            }
-        }
-
-        Ok(config)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use serde_yaml::from_str;
-
-    #[test]
-    fn test_default_config() {
-        let config = Config::default();
-        assert!(config.cloudformation.is_some());
-        let cloudformation = config.cloudformation.unwrap();
-
-        assert!(!cloudformation.enabled(RuleType::LAMBDA_003, "default"));
-        assert!(!cloudformation.enabled(RuleType::LAMBDA_002, "default"));
-        assert!(cloudformation.enabled(RuleType::LAMBDA_001, "default"));
-        assert!(cloudformation.enabled(RuleType::CW_001, "default"));
-        assert!(!cloudformation.enabled(RuleType::CW_003, "default"));
-
-        let cw_log_retention_policy = cloudformation.rules.get(&RuleType::CW_001).unwrap();
-        assert_eq!(
-            cw_log_retention_policy
-                .config_detail
-                .get_threshold_int()
-                .unwrap(),
-            30
+        rules.insert(
+            RuleType::CW_004,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
         );
-    }
-
-    #[test]
-    fn test_load_config() {
-        let file_path = "src/fixtures/cloudsaving.yaml";
-        let config = Config::load(file_path).unwrap();
-        assert!(config.cloudformation.is_some());
-        let cloudformation = config.cloudformation.unwrap();
-
-        let lambda_architecture_arm = cloudformation.rules.get(&RuleType::LAMBDA_002).unwrap();
-        assert!(lambda_architecture_arm.enabled);
-
-        let lambda_missing_tag = cloudformation.rules.get(&RuleType::LAMBDA_003).unwrap();
-        assert_eq!(
-            lambda_missing_tag.config_detail.get_values().unwrap(),
-            &vec!["tag1".to_string(), "tag2".to_string()]
+        rules.insert(
+            RuleType::CW_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(5),
+                },
+            },
         );
-
-        let cw_log_retention_policy = cloudformation.rules.get(&RuleType::CW_001).unwrap();
-        assert_eq!(
-            cw_log_retention_policy
-                .config_detail
-                .get_threshold_int()
-                .unwrap(),
-            14
+        rules.insert(
+            RuleType::CW_006,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
         );
-
-        dbg!(&cloudformation.environments);
-        let default_env = cloudformation
-            .environments
-            .get("default")
-            .unwrap()
-            .as_ref()
-            .unwrap();
-        assert_eq!(default_env.len(), cloudformation.rules.len());
-
-        // No override rules, apply default rules
-        let dev_env = cloudformation
-            .environments
-            .get("dev")
+        rules.insert(
+            RuleType::CW_007,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CW_008,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(3),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::ECS_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ECS_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ECS_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(2048),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::ECS_004,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ECS_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::APIGW_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::APIGW_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Float(0.5),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::APIGW_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::APIGW_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SFN_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SFN_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SFN_003,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::STREAM_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(10),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::STREAM_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::STREAM_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(24),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::STREAM_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::STREAM_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(300),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::MSG_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::MSG_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(345600),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::MSG_003,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Values { values: vec![] },
+            },
+        );
+        rules.insert(
+            RuleType::MSG_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::MSG_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ELASTICACHE_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ELASTICACHE_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ELASTICACHE_003,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(1),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::OPENSEARCH_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::OPENSEARCH_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::OPENSEARCH_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(3),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::OPENSEARCH_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::OPENSEARCH_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(1024),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::CLOUDFRONT_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Values {
+                    values: vec!["PriceClass_100".to_string(), "PriceClass_200".to_string()],
+                },
+            },
+        );
+        rules.insert(
+            RuleType::CLOUDFRONT_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CLOUDFRONT_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CLOUDFRONT_004,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CLOUDFRONT_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ECR_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ECR_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(14),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::FS_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::FS_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::FS_003,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::FS_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(7),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::SECRET_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(5),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::SECRET_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(2),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::SECRET_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SECRET_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SECRET_005,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::GLUE_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::GLUE_002,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(10),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::GLUE_003,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::GLUE_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(4),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::ATHENA_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::ATHENA_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SAGEMAKER_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SAGEMAKER_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SAGEMAKER_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(1),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::SAGEMAKER_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::SCHEDULE_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(288),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::BACKUP_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(35),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::BACKUP_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(180),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::BACKUP_003,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(1),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::BACKUP_004,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(100),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::CODEBUILD_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CODEBUILD_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CODEBUILD_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CODEBUILD_004,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::CODEPIPELINE_001,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(30),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::CODEPIPELINE_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(30),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::REDSHIFT_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(2),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::REDSHIFT_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(32),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::REDSHIFT_003,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::DOCDB_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::Threshold {
+                    threshold: ThresholdValue::Int(1),
+                },
+            },
+        );
+        rules.insert(
+            RuleType::DOCDB_002,
+            RuleTypeConfig {
+                enabled: true,
+                config_detail: RuleTypeConfigDetail::Simple,
+            },
+        );
+        rules.insert(
+            RuleType::TAG_001,
+            RuleTypeConfig {
+                enabled: false,
+                config_detail: RuleTypeConfigDetail::TagPolicy {
+                    policy: TagPolicy::from_keys(&[], TagMatch::All),
+                },
+            },
+        );
+        let mut environments = HashMap::new();
+        environments.insert(
+            "default".to_string(),
+            Some(Environment {
+                extends: None,
+                rules: rules.clone(),
+                sources: rules
+                    .keys()
+                    .map(|rule| (rule.clone(), RuleSource::Default))
+                    .collect(),
+            }),
+        );
+
+        RuleConfig {
+            rules,
+            environments,
+            node_types_file: None,
+            node_types: NodeTypes::default(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub cloudformation: Option<RuleConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cloudformation: Some(RuleConfig::default()),
+        }
+    }
+}
+
+impl Config {
+    pub fn load(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = fs::read_to_string(file_path)?;
+        Self::parse(&data)
+    }
+
+    fn parse(data: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut config: Config = serde_yaml::from_str(data)?;
+
+        // Without a `cloudformation` section every rule keeps its default configuration
+        let cloudformation = config.cloudformation.get_or_insert_with(|| RuleConfig {
+            rules: HashMap::new(),
+            environments: HashMap::new(),
+            node_types_file: None,
+            node_types: NodeTypes::default(),
+        });
+
+        // Merge default rules with the loaded configuration
+        let mut sources = HashMap::new();
+        for (rule_name, default_rule) in RuleConfig::default().rules {
+            let source = if cloudformation.rules.contains_key(&rule_name) {
+                RuleSource::Rules
+            } else {
+                RuleSource::Default
+            };
+            sources.insert(rule_name.clone(), source);
+            cloudformation
+                .rules
+                .entry(rule_name)
+                .or_insert(default_rule);
+        }
+
+        cloudformation.node_types = NodeTypes::load(cloudformation.node_types_file.as_deref())?;
+
+        // Create `default` environment
+        cloudformation
+            .environments
+            .entry("default".to_string())
+            .or_insert(None);
+        cloudformation.resolve_environments(sources)?;
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_yaml::from_str;
+
+    #[test]
+    fn test_config_without_cloudformation() {
+        for data in ["# No sections", "cloudformation:"] {
+            let config = Config::parse(data).unwrap();
+            let cloudformation = config.cloudformation.unwrap();
+            assert_eq!(
+                cloudformation.context("default").rules(),
+                RuleConfig::default().context("default").rules()
+            );
+            assert!(cloudformation.check_environment("prod").is_err());
+        }
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config = Config::default();
+        assert!(config.cloudformation.is_some());
+        let cloudformation = config.cloudformation.unwrap();
+
+        assert!(!cloudformation
+            .context("default")
+            .enabled(RuleType::LAMBDA_003));
+        assert!(!cloudformation
+            .context("default")
+            .enabled(RuleType::LAMBDA_002));
+        assert!(cloudformation
+            .context("default")
+            .enabled(RuleType::LAMBDA_001));
+        assert!(cloudformation.context("default").enabled(RuleType::CW_001));
+        assert!(!cloudformation.context("default").enabled(RuleType::CW_003));
+
+        let cw_log_retention_policy = cloudformation.rules.get(&RuleType::CW_001).unwrap();
+        assert_eq!(
+            cw_log_retention_policy
+                .config_detail
+                .get_threshold_int()
+                .unwrap(),
+            30
+        );
+    }
+
+    #[test]
+    fn test_load_config() {
+        let file_path = "src/fixtures/cloudsaving.yaml";
+        let config = Config::load(file_path).unwrap();
+        assert!(config.cloudformation.is_some());
+        let cloudformation = config.cloudformation.unwrap();
+
+        let lambda_architecture_arm = cloudformation.rules.get(&RuleType::LAMBDA_002).unwrap();
+        assert!(lambda_architecture_arm.enabled);
+
+        let lambda_missing_tag = cloudformation.rules.get(&RuleType::LAMBDA_003).unwrap();
+        assert_eq!(
+            lambda_missing_tag.config_detail.get_values().unwrap(),
+            &vec!["tag1".to_string(), "tag2".to_string()]
+        );
+
+        let cw_log_retention_policy = cloudformation.rules.get(&RuleType::CW_001).unwrap();
+        assert_eq!(
+            cw_log_retention_policy
+                .config_detail
+                .get_threshold_int()
+                .unwrap(),
+            14
+        );
+
+        let tag_policy = cloudformation.rules.get(&RuleType::TAG_001).unwrap();
+        assert_eq!(
+            tag_policy.config_detail.get_tag_policy().unwrap(),
+            &TagPolicy {
+                tags: vec![
+                    TagRequirement {
+                        key: "CostCenter".to_string(),
+                        pattern: None,
+                    },
+                    TagRequirement {
+                        key: "Environment".to_string(),
+                        pattern: Some(Regex::new("^(dev|staging|prod)$").unwrap()),
+                    },
+                ],
+                match_: TagMatch::All,
+            }
+        );
+
+        assert!(cloudformation.node_types.elasticache.is_graviton("r8g"));
+
+        dbg!(&cloudformation.environments);
+        let default_env = cloudformation
+            .environments
+            .get("default")
+            .unwrap()
+            .as_ref()
+            .unwrap();
+        assert_eq!(default_env.rules.len(), cloudformation.rules.len());
+
+        // No override rules, apply default rules
+        let dev_env = cloudformation
+            .environments
+            .get("dev")
             .unwrap()
             .as_ref()
             .unwrap();
-        assert!(dev_env.iter().all(|(k, v)| default_env.get(k) == Some(v)));
+        assert!(dev_env
+            .rules
+            .iter()
+            .all(|(k, v)| default_env.rules.get(k) == Some(v)));
 
         let prod_env = cloudformation
             .environments
@@ -367,15 +1533,147 @@ mod tests {
             .unwrap()
             .as_ref()
             .unwrap();
-        let prod_cw002 = prod_env.get(&RuleType::CW_002).unwrap();
+        let prod_cw002 = prod_env.rules.get(&RuleType::CW_002).unwrap();
         assert!(!prod_cw002.enabled);
-        let prod_lamnda003 = prod_env.get(&RuleType::LAMBDA_003).unwrap();
+        let prod_lamnda003 = prod_env.rules.get(&RuleType::LAMBDA_003).unwrap();
         assert_eq!(
             prod_lamnda003.config_detail.get_values().unwrap(),
             &vec!["tag3".to_string(), "tag4".to_string()]
         );
     }
 
+    #[test]
+    fn test_rule_context() {
+        let config = Config::load("src/fixtures/cloudsaving.yaml").unwrap();
+        let cloudformation = config.cloudformation.unwrap();
+
+        let prod = cloudformation.context("prod");
+        assert_eq!(
+            prod.get(RuleType::LAMBDA_003)
+                .unwrap()
+                .config_detail
+                .get_values()
+                .unwrap(),
+            &vec!["tag3".to_string(), "tag4".to_string()]
+        );
+        assert!(!prod.enabled(RuleType::CW_002));
+        assert!(cloudformation.context("sandbox").enabled(RuleType::CW_003));
+        assert!(!cloudformation.context("default").enabled(RuleType::CW_003));
+
+        // Environments that aren't defined have no rules, and are rejected
+        let qa = cloudformation.context("qa");
+        assert!(!qa.enabled(RuleType::LAMBDA_001));
+        assert!(qa.rules().is_empty());
+        assert!(cloudformation.check_environment("prod").is_ok());
+        assert_eq!(
+            cloudformation.check_environment("qa").unwrap_err(),
+            "environment `qa` is not defined, expected one of: default, dev, perf, prod, sandbox, staging"
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let config = Config::load("src/fixtures/cloudsaving.yaml").unwrap();
+        let cloudformation = config.cloudformation.unwrap();
+        let explained = cloudformation.explain("perf").unwrap();
+
+        // Only the selected environment is written, in the config file format
+        let environments: HashMap<String, Environment> = from_str(&explained).unwrap();
+        assert_eq!(environments.len(), 1);
+        let expected = cloudformation.environments["perf"].as_ref().unwrap();
+        assert_eq!(environments["perf"].rules, expected.rules);
+        assert_eq!(environments["perf"].extends, expected.extends);
+
+        // Each rule notes where its configuration came from
+        let explained: serde_yaml::Value = from_str(&explained).unwrap();
+        let source = |environment: &str, rule: &str| {
+            explained[environment][rule]["source"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(source("perf", "LAMBDA_003"), "environments.prod");
+        assert_eq!(source("perf", "LAMBDA_006"), "environments.staging");
+        assert_eq!(source("perf", "CW_001"), "rules");
+        assert_eq!(source("perf", "LAMBDA_008"), "default");
+        assert_eq!(explained["perf"]["extends"], "staging");
+    }
+
+    #[test]
+    fn test_environment_inheritance() {
+        let config = Config::load("src/fixtures/cloudsaving.yaml").unwrap();
+        let cloudformation = config.cloudformation.unwrap();
+        let prod = cloudformation.context("prod");
+        let staging = cloudformation.context("staging");
+        let perf = cloudformation.context("perf");
+
+        // `staging` is `prod` with LAMBDA_006 relaxed
+        assert_eq!(
+            staging.get(RuleType::LAMBDA_003),
+            prod.get(RuleType::LAMBDA_003)
+        );
+        assert!(!staging.enabled(RuleType::CW_002));
+        assert!(prod.enabled(RuleType::LAMBDA_006));
+        assert!(!staging.enabled(RuleType::LAMBDA_006));
+
+        // `perf` extends `staging`
+        assert!(!perf.enabled(RuleType::LAMBDA_006));
+        assert!(!perf.enabled(RuleType::CW_002));
+        assert!(perf.enabled(RuleType::CW_003));
+        assert!(!staging.enabled(RuleType::CW_003));
+    }
+
+    #[test]
+    fn test_environment_inheritance_errors() {
+        let parse_error = |yaml: &str| Config::parse(yaml).unwrap_err().to_string();
+
+        let error = parse_error(
+            r#"
+        cloudformation:
+          rules: {}
+          environments:
+            staging:
+              extends: prd
+        "#,
+        );
+        assert_eq!(
+            error,
+            "environment `staging` extends `prd`, which is not defined"
+        );
+
+        let error = parse_error(
+            r#"
+        cloudformation:
+          rules: {}
+          environments:
+            prod:
+              extends: perf
+            staging:
+              extends: prod
+            perf:
+              extends: staging
+        "#,
+        );
+        assert!(error.starts_with("environments extend each other in a cycle: "));
+        for environment in ["prod", "staging", "perf"] {
+            assert!(error.contains(environment));
+        }
+
+        let error = parse_error(
+            r#"
+        cloudformation:
+          rules: {}
+          environments:
+            prod:
+              extends: prod
+        "#,
+        );
+        assert_eq!(
+            error,
+            "environments extend each other in a cycle: prod -> prod"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "missing field `enabled`")]
     fn test_invalid_config_missing_enabled() {
@@ -389,13 +1687,34 @@ mod tests {
         let _: Config = from_str(yaml).unwrap();
     }
 
+    #[test]
+    #[should_panic(expected = "invalid pattern for tag `Owner`")]
+    fn test_invalid_config_tag_pattern() {
+        let yaml = r#"
+        cloudformation:
+          rules:
+            TAG_001:
+              enabled: true
+              match: any
+              tags:
+                - key: Owner
+                  pattern: "[a-z"
+        "#;
+
+        let _: Config = from_str(yaml).unwrap();
+    }
+
     #[test]
     fn test_default_environment() {
         let config = Config::default();
         let cloudformation = config.cloudformation.unwrap();
 
         // Test with default environment
-        assert!(cloudformation.enabled(RuleType::LAMBDA_001, "default"));
-        assert!(!cloudformation.enabled(RuleType::LAMBDA_003, "default"));
+        assert!(cloudformation
+            .context("default")
+            .enabled(RuleType::LAMBDA_001));
+        assert!(!cloudformation
+            .context("default")
+            .enabled(RuleType::LAMBDA_003));
     }
 }
@@ -0,0 +1,315 @@
+use crate::parsers::cfn::{get_att, get_ref, CloudFormation};
+use crate::parsers::iac::IaCResource;
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// How one resource refers to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum EdgeKind {
+    Ref,
+    GetAtt,
+    Sub,
+    DependsOn,
+    /// A SAM event source declared under the function's `Events`
+    Event,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, Serialize)]
+struct Node<'a> {
+    id: &'a str,
+    #[serde(rename = "type")]
+    type_: &'a str,
+}
+
+#[derive(Debug, Default)]
+pub struct ResourceGraph {
+    /// Resource types keyed by logical ID, in template order
+    nodes: IndexMap<String, String>,
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+impl ResourceGraph {
+    pub fn from_cloudformation(cloudformation: &CloudFormation) -> Self {
+        let Some(resources) = &cloudformation.resources else {
+            return Self::default();
+        };
+        let nodes: IndexMap<String, String> = resources
+            .iter()
+            .map(|(key, resource)| (key.clone(), resource.get_type()))
+            .collect();
+
+        let mut edges = BTreeSet::new();
+        for (key, resource) in resources {
+            let mut add_edge = |to: &str, kind: EdgeKind| {
+                // Parameters, pseudo parameters and self references are not resources
+                if to != key && nodes.contains_key(to) {
+                    edges.insert(Edge {
+                        from: key.clone(),
+                        to: to.to_string(),
+                        kind,
+                    });
+                }
+            };
+            if let Some(properties) = &resource.properties {
+                for (name, value) in properties {
+                    collect_references(value, &mut |to, kind| {
+                        // Everything referenced from SAM events is an event source
+                        let kind = if name == "Events" {
+                            EdgeKind::Event
+                        } else {
+                            kind
+                        };
+                        add_edge(to, kind)
+                    });
+                }
+            }
+            match resource.other.get("DependsOn") {
+                Some(serde_yaml::Value::String(depends_on)) => {
+                    add_edge(depends_on, EdgeKind::DependsOn)
+                }
+                Some(serde_yaml::Value::Sequence(depends_on)) => depends_on
+                    .iter()
+                    .filter_map(|d| d.as_str())
+                    .for_each(|d| add_edge(d, EdgeKind::DependsOn)),
+                _ => {}
+            }
+        }
+
+        Self {
+            nodes,
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    /// Returns the edges from resources that refer to `logical_id`.
+    pub fn dependents<'a>(&'a self, logical_id: &'a str) -> impl Iterator<Item = &'a Edge> {
+        self.edges.iter().filter(move |edge| edge.to == logical_id)
+    }
+
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Json => self.to_json(),
+        }
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph resources {\n    node [shape=box];\n");
+        for (id, type_) in &self.nodes {
+            dot.push_str(&format!("    \"{}\" [label=\"{}\\n{}\"];\n", id, id, type_));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{:?}\"];\n",
+                edge.from, edge.to, edge.kind
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct Graph<'a> {
+            nodes: Vec<Node<'a>>,
+            edges: &'a [Edge],
+        }
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(id, type_)| Node { id, type_ })
+            .collect();
+        serde_json::to_string_pretty(&Graph {
+            nodes,
+            edges: &self.edges,
+        })
+        .expect("Failed to serialize resource graph")
+    }
+}
+
+/// Returns the logical IDs referenced by `!Ref`, `!GetAtt` or `!Sub` in `value`. These can
+/// include parameters and pseudo parameters, which are not resources.
+pub fn references(value: &serde_yaml::Value) -> Vec<String> {
+    let mut references = Vec::new();
+    collect_references(value, &mut |logical_id, _| {
+        references.push(logical_id.to_string())
+    });
+    references
+}
+
+/// Calls `add_edge` for every logical ID referenced by `!Ref`, `!GetAtt` or `!Sub` in `value`.
+fn collect_references(value: &serde_yaml::Value, add_edge: &mut dyn FnMut(&str, EdgeKind)) {
+    if let Some(logical_id) = get_ref(value) {
+        add_edge(logical_id, EdgeKind::Ref);
+        return;
+    }
+    if let Some((logical_id, _)) = get_att(value) {
+        add_edge(logical_id, EdgeKind::GetAtt);
+        return;
+    }
+    match value {
+        serde_yaml::Value::Tagged(tagged_value) if tagged_value.tag == "!Sub" => {
+            collect_sub_references(&tagged_value.value, add_edge)
+        }
+        serde_yaml::Value::Tagged(tagged_value) => {
+            collect_references(&tagged_value.value, add_edge)
+        }
+        serde_yaml::Value::Mapping(mapping) => {
+            if let Some(sub) = mapping.get("Fn::Sub") {
+                collect_sub_references(sub, add_edge);
+            } else {
+                mapping
+                    .values()
+                    .for_each(|value| collect_references(value, add_edge));
+            }
+        }
+        serde_yaml::Value::Sequence(seq) => seq
+            .iter()
+            .for_each(|value| collect_references(value, add_edge)),
+        _ => {}
+    }
+}
+
+/// Collects the `${LogicalId}` and `${LogicalId.Attribute}` variables of a `Fn::Sub`, skipping
+/// variables bound in the optional variable map.
+fn collect_sub_references(sub: &serde_yaml::Value, add_edge: &mut dyn FnMut(&str, EdgeKind)) {
+    let (template, variables) = match sub {
+        serde_yaml::Value::String(template) => (template.as_str(), None),
+        serde_yaml::Value::Sequence(seq) => {
+            let Some(template) = seq.first().and_then(|t| t.as_str()) else {
+                return;
+            };
+            (template, seq.get(1).and_then(|v| v.as_mapping()))
+        }
+        _ => return,
+    };
+    for variable in template
+        .split("${")
+        .skip(1)
+        .filter_map(|s| s.split_once('}').map(|(variable, _)| variable))
+    {
+        // `${!Literal}` escapes a variable
+        if variable.starts_with('!') {
+            continue;
+        }
+        if variables.is_some_and(|vars| vars.contains_key(variable)) {
+            continue;
+        }
+        let logical_id = variable.split('.').next().unwrap_or(variable);
+        add_edge(logical_id, EdgeKind::Sub);
+    }
+    if let Some(variables) = variables {
+        variables
+            .values()
+            .for_each(|value| collect_references(value, add_edge));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::cfn::parse_cloudformation;
+
+    fn edge(from: &str, to: &str, kind: EdgeKind) -> Edge {
+        Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind,
+        }
+    }
+
+    fn dependencies<'a>(graph: &'a ResourceGraph, logical_id: &str) -> Vec<&'a Edge> {
+        graph
+            .edges
+            .iter()
+            .filter(|edge| edge.from == logical_id)
+            .collect()
+    }
+
+    #[test]
+    fn test_resource_graph_edges() {
+        let cloudformation =
+            parse_cloudformation("src/fixtures/aws/cfn-lambda-event-sources.yaml").unwrap();
+        let graph = ResourceGraph::from_cloudformation(&cloudformation);
+        let edges = dependencies(&graph, "MyEventSourceMapping");
+        assert_eq!(
+            edges,
+            vec![
+                &edge("MyEventSourceMapping", "MyLambdaFunction3", EdgeKind::Ref),
+                &edge("MyEventSourceMapping", "MyStream", EdgeKind::GetAtt),
+            ]
+        );
+        let edges: Vec<&Edge> = graph.dependents("MyQueue").collect();
+        assert_eq!(
+            edges,
+            vec![
+                &edge("MyEventSourceMapping2", "MyQueue", EdgeKind::GetAtt),
+                &edge("MyLambdaFunction", "MyQueue", EdgeKind::Event),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resource_graph_sub_and_depends_on() {
+        let cloudformation =
+            parse_cloudformation("src/fixtures/aws/cfn-lambda-log-groups.yaml").unwrap();
+        let graph = ResourceGraph::from_cloudformation(&cloudformation);
+        let edges: Vec<&Edge> = graph.dependents("MyLambdaFunction").collect();
+        assert_eq!(
+            edges,
+            vec![&edge("MyLogGroup", "MyLambdaFunction", EdgeKind::Sub)]
+        );
+        let edges = dependencies(&graph, "MyLogGroup3");
+        assert_eq!(
+            edges,
+            vec![&edge(
+                "MyLogGroup3",
+                "MyLambdaFunction3",
+                EdgeKind::DependsOn
+            )]
+        );
+        // Pseudo parameters are not resources
+        assert!(dependencies(&graph, "MyLogGroup2").is_empty());
+    }
+
+    #[test]
+    fn test_references() {
+        let value: serde_yaml::Value = serde_yaml::from_str(
+            "!Sub [\"arn:aws:codepipeline:${AWS::Region}:${AWS::AccountId}:${Pipeline}\", {Pipeline: !Ref MyPipeline}]",
+        )
+        .unwrap();
+        assert_eq!(
+            references(&value),
+            vec!["AWS::Region", "AWS::AccountId", "MyPipeline"]
+        );
+    }
+
+    #[test]
+    fn test_resource_graph_render() {
+        let cloudformation =
+            parse_cloudformation("src/fixtures/aws/cfn-lambda-log-groups.yaml").unwrap();
+        let graph = ResourceGraph::from_cloudformation(&cloudformation);
+        let dot = graph.render(GraphFormat::Dot);
+        assert!(dot.starts_with("digraph resources {"));
+        assert!(dot.contains("\"MyLogGroup\" -> \"MyLambdaFunction\" [label=\"Sub\"];"));
+        let json: serde_json::Value =
+            serde_json::from_str(&graph.render(GraphFormat::Json)).unwrap();
+        assert_eq!(json["nodes"][0]["id"], "MyLambdaFunction");
+        assert_eq!(json["nodes"][0]["type"], "AWS::Serverless::Function");
+        assert_eq!(json["edges"][0]["kind"], "Sub");
+    }
+}
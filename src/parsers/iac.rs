@@ -1,9 +1,25 @@
 use crate::parsers::cfn::CloudFormation;
+use crate::parsers::graph::ResourceGraph;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub struct InfratructureTemplate {
     pub cloudformation: Option<CloudFormation>,
+    /// References between resources, for rules that reason across resources
+    pub graph: ResourceGraph,
+}
+
+impl InfratructureTemplate {
+    pub fn new(cloudformation: Option<CloudFormation>) -> Self {
+        let graph = cloudformation
+            .as_ref()
+            .map(ResourceGraph::from_cloudformation)
+            .unwrap_or_default();
+        Self {
+            cloudformation,
+            graph,
+        }
+    }
 }
 
 #[allow(unused)]
@@ -35,10 +51,94 @@ pub trait IaCOutput {
 pub enum AWSResourceType {
     LambdaFunction,
     LambdaServerlessFunction,
+    LambdaAlias,
+    LambdaVersion,
+    LambdaEventSourceMapping,
     CloudWatch,
+    CloudWatchAlarm,
+    CloudWatchDashboard,
+    LogsMetricFilter,
+    LogsSubscriptionFilter,
+    EcsTaskDefinition,
+    EcsService,
+    EcsCluster,
+    ServerlessApi,
+    ServerlessHttpApi,
+    StepFunctionsStateMachine,
+    ServerlessStateMachine,
+    KinesisStream,
+    KinesisFirehoseDeliveryStream,
+    MskCluster,
+    SqsQueue,
+    SnsTopic,
+    SnsSubscription,
+    EventsRule,
+    EventsArchive,
+    SchedulerSchedule,
+    ElastiCacheCacheCluster,
+    ElastiCacheReplicationGroup,
+    ElastiCacheServerlessCache,
+    OpenSearchDomain,
+    CloudFrontDistribution,
+    S3Bucket,
+    EcrRepository,
+    EfsFileSystem,
+    EfsAccessPoint,
+    FsxFileSystem,
+    SecretsManagerSecret,
+    SecretsManagerRotationSchedule,
+    KmsKey,
+    KmsAlias,
+    SsmParameter,
+    GlueJob,
+    GlueCrawler,
+    AthenaWorkGroup,
+    SageMakerEndpoint,
+    SageMakerEndpointConfig,
+    SageMakerNotebookInstance,
+    SageMakerDomain,
+    ApplicationAutoScalingScalableTarget,
+    BackupPlan,
+    BackupVault,
+    RdsDbInstance,
+    RdsDbCluster,
+    Ec2Volume,
+    CodeBuildProject,
+    CodePipelinePipeline,
+    RedshiftCluster,
+    RedshiftServerlessWorkgroup,
+    DocDbCluster,
+    DocDbInstance,
     Unknown(String),
 }
 
+impl AWSResourceType {
+    /// Returns the property holding the resource's tags, or `None` if the type can't be tagged.
+    /// Unknown types are only known to be taggable when they declare `Tags`.
+    pub fn tags_property(&self) -> Option<&'static str> {
+        match self {
+            Self::LambdaAlias
+            | Self::LambdaVersion
+            | Self::CloudWatchDashboard
+            | Self::LogsMetricFilter
+            | Self::LogsSubscriptionFilter
+            | Self::SnsSubscription
+            | Self::EventsRule
+            | Self::EventsArchive
+            | Self::SchedulerSchedule
+            | Self::SecretsManagerRotationSchedule
+            | Self::KmsAlias
+            | Self::ApplicationAutoScalingScalableTarget
+            | Self::Unknown(_) => None,
+            Self::EfsFileSystem => Some("FileSystemTags"),
+            Self::EfsAccessPoint => Some("AccessPointTags"),
+            Self::BackupPlan => Some("BackupPlanTags"),
+            Self::BackupVault => Some("BackupVaultTags"),
+            _ => Some("Tags"),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for AWSResourceType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -48,7 +148,66 @@ impl<'de> Deserialize<'de> for AWSResourceType {
         let resource_type = match cfn_type.to_uppercase().as_str() {
             "AWS::LAMBDA::FUNCTION" => Self::LambdaFunction,
             "AWS::SERVERLESS::FUNCTION" => Self::LambdaServerlessFunction,
+            "AWS::LAMBDA::ALIAS" => Self::LambdaAlias,
+            "AWS::LAMBDA::VERSION" => Self::LambdaVersion,
+            "AWS::LAMBDA::EVENTSOURCEMAPPING" => Self::LambdaEventSourceMapping,
             "AWS::LOGS::LOGGROUP" => Self::CloudWatch,
+            "AWS::CLOUDWATCH::ALARM" => Self::CloudWatchAlarm,
+            "AWS::CLOUDWATCH::DASHBOARD" => Self::CloudWatchDashboard,
+            "AWS::LOGS::METRICFILTER" => Self::LogsMetricFilter,
+            "AWS::LOGS::SUBSCRIPTIONFILTER" => Self::LogsSubscriptionFilter,
+            "AWS::ECS::TASKDEFINITION" => Self::EcsTaskDefinition,
+            "AWS::ECS::SERVICE" => Self::EcsService,
+            "AWS::ECS::CLUSTER" => Self::EcsCluster,
+            "AWS::SERVERLESS::API" => Self::ServerlessApi,
+            "AWS::SERVERLESS::HTTPAPI" => Self::ServerlessHttpApi,
+            "AWS::STEPFUNCTIONS::STATEMACHINE" => Self::StepFunctionsStateMachine,
+            "AWS::SERVERLESS::STATEMACHINE" => Self::ServerlessStateMachine,
+            "AWS::KINESIS::STREAM" => Self::KinesisStream,
+            "AWS::KINESISFIREHOSE::DELIVERYSTREAM" => Self::KinesisFirehoseDeliveryStream,
+            "AWS::MSK::CLUSTER" => Self::MskCluster,
+            "AWS::SQS::QUEUE" => Self::SqsQueue,
+            "AWS::SNS::TOPIC" => Self::SnsTopic,
+            "AWS::SNS::SUBSCRIPTION" => Self::SnsSubscription,
+            "AWS::EVENTS::RULE" => Self::EventsRule,
+            "AWS::EVENTS::ARCHIVE" => Self::EventsArchive,
+            "AWS::SCHEDULER::SCHEDULE" => Self::SchedulerSchedule,
+            "AWS::ELASTICACHE::CACHECLUSTER" => Self::ElastiCacheCacheCluster,
+            "AWS::ELASTICACHE::REPLICATIONGROUP" => Self::ElastiCacheReplicationGroup,
+            "AWS::ELASTICACHE::SERVERLESSCACHE" => Self::ElastiCacheServerlessCache,
+            "AWS::OPENSEARCHSERVICE::DOMAIN" => Self::OpenSearchDomain,
+            "AWS::CLOUDFRONT::DISTRIBUTION" => Self::CloudFrontDistribution,
+            "AWS::S3::BUCKET" => Self::S3Bucket,
+            "AWS::ECR::REPOSITORY" => Self::EcrRepository,
+            "AWS::EFS::FILESYSTEM" => Self::EfsFileSystem,
+            "AWS::EFS::ACCESSPOINT" => Self::EfsAccessPoint,
+            "AWS::FSX::FILESYSTEM" => Self::FsxFileSystem,
+            "AWS::SECRETSMANAGER::SECRET" => Self::SecretsManagerSecret,
+            "AWS::SECRETSMANAGER::ROTATIONSCHEDULE" => Self::SecretsManagerRotationSchedule,
+            "AWS::KMS::KEY" => Self::KmsKey,
+            "AWS::KMS::ALIAS" => Self::KmsAlias,
+            "AWS::SSM::PARAMETER" => Self::SsmParameter,
+            "AWS::GLUE::JOB" => Self::GlueJob,
+            "AWS::GLUE::CRAWLER" => Self::GlueCrawler,
+            "AWS::ATHENA::WORKGROUP" => Self::AthenaWorkGroup,
+            "AWS::SAGEMAKER::ENDPOINT" => Self::SageMakerEndpoint,
+            "AWS::SAGEMAKER::ENDPOINTCONFIG" => Self::SageMakerEndpointConfig,
+            "AWS::SAGEMAKER::NOTEBOOKINSTANCE" => Self::SageMakerNotebookInstance,
+            "AWS::SAGEMAKER::DOMAIN" => Self::SageMakerDomain,
+            "AWS::APPLICATIONAUTOSCALING::SCALABLETARGET" => {
+                Self::ApplicationAutoScalingScalableTarget
+            }
+            "AWS::BACKUP::BACKUPPLAN" => Self::BackupPlan,
+            "AWS::BACKUP::BACKUPVAULT" => Self::BackupVault,
+            "AWS::RDS::DBINSTANCE" => Self::RdsDbInstance,
+            "AWS::RDS::DBCLUSTER" => Self::RdsDbCluster,
+            "AWS::EC2::VOLUME" => Self::Ec2Volume,
+            "AWS::CODEBUILD::PROJECT" => Self::CodeBuildProject,
+            "AWS::CODEPIPELINE::PIPELINE" => Self::CodePipelinePipeline,
+            "AWS::REDSHIFT::CLUSTER" => Self::RedshiftCluster,
+            "AWS::REDSHIFTSERVERLESS::WORKGROUP" => Self::RedshiftServerlessWorkgroup,
+            "AWS::DOCDB::DBCLUSTER" => Self::DocDbCluster,
+            "AWS::DOCDB::DBINSTANCE" => Self::DocDbInstance,
             _ => Self::Unknown(cfn_type),
         };
         Ok(resource_type)
@@ -1,6 +1,9 @@
 pub(crate) mod cfn;
 pub(crate) mod config;
+pub(crate) mod graph;
 pub(crate) mod iac;
+pub(crate) mod node_types;
+pub(crate) mod schedule;
 use marked_yaml::{parse_yaml, Node, Span};
 use regex::Regex;
 use std::fs;
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+
+/// The built-in node type tables, shipped with the binary.
+const DEFAULT_NODE_TYPES: &str = include_str!("data/node-types.yaml");
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct NodeTypes {
+    pub elasticache: NodeTypeTable,
+    pub opensearch: NodeTypeTable,
+    pub docdb: NodeTypeTable,
+}
+
+/// The contents of a `node_types` override file. Tables left out keep their built-in values.
+#[derive(Debug, Deserialize)]
+struct NodeTypesOverride {
+    elasticache: Option<NodeTypeTable>,
+    opensearch: Option<NodeTypeTable>,
+    docdb: Option<NodeTypeTable>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct NodeTypeTable {
+    /// Superseded instance families, mapped to the suggested replacement family
+    #[serde(default)]
+    pub previous_generation: HashMap<String, String>,
+    /// Instance families running on AWS Graviton processors
+    #[serde(default)]
+    pub graviton: Vec<String>,
+}
+
+impl NodeTypes {
+    /// Loads the built-in node type tables, replacing each table defined in `file_path`.
+    pub fn load(file_path: Option<&str>) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut node_types = Self::default();
+        if let Some(file_path) = file_path {
+            let data = fs::read_to_string(file_path)?;
+            let overrides: NodeTypesOverride = serde_yaml::from_str(&data)?;
+            if let Some(elasticache) = overrides.elasticache {
+                node_types.elasticache = elasticache;
+            }
+            if let Some(opensearch) = overrides.opensearch {
+                node_types.opensearch = opensearch;
+            }
+            if let Some(docdb) = overrides.docdb {
+                node_types.docdb = docdb;
+            }
+        }
+        Ok(node_types)
+    }
+}
+
+impl Default for NodeTypes {
+    fn default() -> Self {
+        serde_yaml::from_str(DEFAULT_NODE_TYPES).expect("Invalid built-in node type tables")
+    }
+}
+
+impl NodeTypeTable {
+    /// Returns the replacement family when `family` is a previous generation family.
+    pub fn previous_generation(&self, family: &str) -> Option<&String> {
+        self.previous_generation.get(family)
+    }
+
+    pub fn is_graviton(&self, family: &str) -> bool {
+        self.graviton.iter().any(|graviton| graviton == family)
+    }
+}
+
+/// Returns the instance family of a node type, e.g. `r6g` for `cache.r6g.large`,
+/// `m5` for `m5.large.search` or `r5` for `db.r5.large`.
+pub(crate) fn instance_family(node_type: &str) -> Option<&str> {
+    node_type
+        .strip_prefix("cache.")
+        .or_else(|| node_type.strip_prefix("db."))
+        .unwrap_or(node_type)
+        .split('.')
+        .next()
+        .filter(|family| !family.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_node_types() {
+        let node_types = NodeTypes::default();
+        assert_eq!(
+            node_types.elasticache.previous_generation("r4"),
+            Some(&"r7g".to_string())
+        );
+        assert!(node_types.elasticache.is_graviton("r6g"));
+        assert!(!node_types.opensearch.is_graviton("m5"));
+        assert!(node_types.docdb.is_graviton("r6g"));
+        assert_eq!(NodeTypes::load(None).unwrap(), node_types);
+    }
+
+    #[test]
+    fn test_load_node_types_override() {
+        let node_types = NodeTypes::load(Some("src/fixtures/node-types.yaml")).unwrap();
+        assert!(node_types.elasticache.is_graviton("r8g"));
+        assert_eq!(node_types.elasticache.previous_generation("r5"), None);
+        assert!(node_types.opensearch.previous_generation.is_empty());
+        // Tables missing from an override file keep their built-in values
+        assert_eq!(node_types.docdb, NodeTypes::default().docdb);
+    }
+
+    #[test]
+    fn test_instance_family() {
+        assert_eq!(instance_family("cache.r6g.large"), Some("r6g"));
+        assert_eq!(instance_family("m5.large.search"), Some("m5"));
+        assert_eq!(instance_family("db.r5.large"), Some("r5"));
+        assert_eq!(instance_family(""), None);
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeSet;
+
+const DAYS_PER_YEAR: f64 = 365.25;
+const DAYS_PER_MONTH: f64 = DAYS_PER_YEAR / 12.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const DAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A `rate(...)`, `cron(...)` or `at(...)` schedule expression, as used by EventBridge rules,
+/// EventBridge Scheduler and Glue triggers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleExpression {
+    /// Fires every `minutes` minutes
+    Rate {
+        minutes: u64,
+    },
+    Cron(CronExpression),
+    /// Fires once
+    At,
+}
+
+/// A six field AWS cron expression: minutes, hours, day of month, month, day of week and year.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronExpression {
+    minutes: usize,
+    hours: usize,
+    /// Average number of days per year the expression fires on
+    days_per_year: f64,
+}
+
+impl ScheduleExpression {
+    pub fn parse(expression: &str) -> Option<Self> {
+        let expression = expression.trim();
+        let (function, args) = expression.strip_suffix(')')?.split_once('(')?;
+        match function.trim() {
+            "rate" => parse_rate(args),
+            "cron" => CronExpression::parse(args).map(Self::Cron),
+            "at" => Some(Self::At),
+            _ => None,
+        }
+    }
+
+    /// Returns how many times the schedule fires per day, averaged over a year.
+    pub fn runs_per_day(&self) -> f64 {
+        match self {
+            Self::Rate { minutes } => 24.0 * 60.0 / *minutes as f64,
+            Self::Cron(cron) => cron.runs_per_day(),
+            Self::At => 0.0,
+        }
+    }
+
+    /// Returns how many times the schedule fires in an average month.
+    pub fn runs_per_month(&self) -> f64 {
+        self.runs_per_day() * DAYS_PER_MONTH
+    }
+}
+
+fn parse_rate(args: &str) -> Option<ScheduleExpression> {
+    let (value, unit) = args.trim().split_once(' ')?;
+    let value: u64 = value.parse().ok().filter(|value| *value > 0)?;
+    let minutes = match unit.trim() {
+        "minute" | "minutes" => value,
+        "hour" | "hours" => value * 60,
+        "day" | "days" => value * 60 * 24,
+        _ => return None,
+    };
+    Some(ScheduleExpression::Rate { minutes })
+}
+
+impl CronExpression {
+    fn parse(args: &str) -> Option<Self> {
+        let fields: Vec<&str> = args.split_whitespace().collect();
+        let [minutes, hours, day_of_month, month, day_of_week, _year] = fields[..] else {
+            return None;
+        };
+        let minutes = expand_field(minutes, 0, 59, &[])?.len();
+        let hours = expand_field(hours, 0, 23, &[])?.len();
+        let months = expand_field(month, 1, 12, &MONTH_NAMES)?.len();
+
+        // `L`, `W` and `#` select a single day of the month
+        let days_per_month = if day_of_month.contains(['L', 'W']) {
+            1.0
+        } else if !matches!(day_of_month, "*" | "?") {
+            expand_field(day_of_month, 1, 31, &[])?.len() as f64
+        } else if day_of_week.contains(['L', '#']) {
+            1.0
+        } else {
+            let days = expand_field(day_of_week, 1, 7, &DAY_NAMES)?.len();
+            days as f64 * DAYS_PER_MONTH / 7.0
+        };
+
+        Some(Self {
+            minutes,
+            hours,
+            days_per_year: months as f64 * days_per_month,
+        })
+    }
+
+    pub fn runs_per_day(&self) -> f64 {
+        (self.minutes * self.hours) as f64 * self.days_per_year / DAYS_PER_YEAR
+    }
+}
+
+/// Expands a cron field such as `*/15`, `1-5` or `MON,WED` to the values it matches.
+fn expand_field(field: &str, min: u32, max: u32, names: &[&str]) -> Option<BTreeSet<u32>> {
+    let value = |s: &str| -> Option<u32> {
+        let upper = s.to_uppercase();
+        let value = match names.iter().position(|name| *name == upper) {
+            Some(index) => index as u32 + min,
+            None => s.parse().ok()?,
+        };
+        (min..=max).contains(&value).then_some(value)
+    };
+
+    let mut values = BTreeSet::new();
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse().ok().filter(|step| *step > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" | "?" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                // `5/10` starts at 5 and runs to the end of the range, even with a step of 1
+                None if part.contains('/') => (value(range)?, max),
+                None => (value(range)?, value(range)?),
+            },
+        };
+        if start <= end {
+            values.extend((start..=end).step_by(step));
+        } else {
+            // Ranges such as `FRI-MON` wrap around
+            values.extend((start..=max).chain(min..=end).step_by(step));
+        }
+    }
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rate() {
+        assert_eq!(
+            ScheduleExpression::parse("rate(1 minute)"),
+            Some(ScheduleExpression::Rate { minutes: 1 })
+        );
+        assert_eq!(
+            ScheduleExpression::parse("rate(2 hours)"),
+            Some(ScheduleExpression::Rate { minutes: 120 })
+        );
+        assert_eq!(
+            ScheduleExpression::parse("rate(1 minute)")
+                .unwrap()
+                .runs_per_month(),
+            43830.0
+        );
+        assert_eq!(ScheduleExpression::parse("rate(0 minutes)"), None);
+        assert_eq!(ScheduleExpression::parse("rate(5 weeks)"), None);
+        assert_eq!(
+            ScheduleExpression::parse("rate(5 minutes)")
+                .unwrap()
+                .runs_per_day(),
+            288.0
+        );
+    }
+
+    #[test]
+    fn test_parse_cron() {
+        let assert_runs_per_day = |expression: &str, expected: f64| {
+            let runs_per_day = ScheduleExpression::parse(expression)
+                .unwrap()
+                .runs_per_day();
+            assert!(
+                (runs_per_day - expected).abs() < 1e-9,
+                "{} runs {} times per day, expected {}",
+                expression,
+                runs_per_day,
+                expected
+            );
+        };
+        assert_runs_per_day("cron(0 * * * ? *)", 24.0);
+        assert_runs_per_day("cron(*/15 * * * ? *)", 96.0);
+        assert_runs_per_day("cron(0/1 * * * ? *)", 1440.0);
+        assert_runs_per_day("cron(30/1 * * * ? *)", 720.0);
+        assert_runs_per_day("cron(0 12 * * ? *)", 1.0);
+        assert_runs_per_day("cron(0 8-17 ? * MON-FRI *)", 10.0 * 5.0 / 7.0);
+        assert_runs_per_day("cron(0 0 1 * ? *)", 12.0 / DAYS_PER_YEAR);
+        assert_runs_per_day("cron(0 0 ? * 6L *)", 12.0 / DAYS_PER_YEAR);
+        assert_runs_per_day("cron(0 0 ? * FRI-MON *)", 4.0 / 7.0);
+        assert_eq!(ScheduleExpression::parse("cron(0 12 * * ?)"), None);
+        assert_eq!(ScheduleExpression::parse("cron(0 25 * * ? *)"), None);
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let schedule = ScheduleExpression::parse("at(2026-01-01T00:00:00)").unwrap();
+        assert_eq!(schedule, ScheduleExpression::At);
+        assert_eq!(schedule.runs_per_day(), 0.0);
+        assert_eq!(ScheduleExpression::parse("every 5 minutes"), None);
+    }
+}
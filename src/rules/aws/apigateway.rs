@@ -0,0 +1,186 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::aws::cloudwatch::{find_log_group, has_log_retention};
+use crate::rules::violations::ApiGatewayViolation;
+use std::collections::HashMap;
+
+/// Whether a SAM REST API uses a feature that HTTP APIs do not support.
+fn uses_rest_only_features(properties: &HashMap<String, serde_yaml::Value>) -> bool {
+    let is_true = |value: Option<&serde_yaml::Value>| value.and_then(|v| v.as_bool()) == Some(true);
+    let endpoint_type = properties.get("EndpointConfiguration").and_then(|config| {
+        config
+            .as_str()
+            .or_else(|| config.get("Type").and_then(|t| t.as_str()))
+    });
+    let caching_enabled = properties
+        .get("MethodSettings")
+        .and_then(|settings| settings.as_sequence())
+        .is_some_and(|settings| {
+            settings
+                .iter()
+                .any(|setting| is_true(setting.get("CachingEnabled")))
+        });
+    let auth = properties.get("Auth");
+
+    is_true(properties.get("CacheClusterEnabled"))
+        || caching_enabled
+        || is_true(properties.get("TracingEnabled"))
+        || matches!(endpoint_type, Some("EDGE") | Some("PRIVATE"))
+        || is_true(auth.and_then(|a| a.get("ApiKeyRequired")))
+        || auth.is_some_and(|a| a.get("UsagePlan").is_some() || a.get("ResourcePolicy").is_some())
+        || [
+            "Models",
+            "CanarySetting",
+            "GatewayResponses",
+            "MinimumCompressionSize",
+        ]
+        .iter()
+        .any(|feature| properties.contains_key(*feature))
+}
+
+pub fn check_apigateway_http_api_candidate<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::ServerlessApi = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        if !uses_rest_only_features(properties) {
+                            error_reporter.add_error(
+                                Box::new(ApiGatewayViolation::HttpApiCandidate),
+                                key,
+                                line_marker.get_resource_span(vec![key]).copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_apigateway_cache_cluster_size<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_cache_cluster_size = rule_context
+            .get(RuleType::APIGW_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_number())
+            .unwrap_or(0.5);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::ServerlessApi = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        if properties
+                            .get("CacheClusterEnabled")
+                            .and_then(|v| v.as_bool())
+                            != Some(true)
+                        {
+                            continue;
+                        }
+                        // The cache cluster size defaults to 0.5 GB
+                        let cache_cluster_size = properties
+                            .get("CacheClusterSize")
+                            .and_then(|size| {
+                                size.as_f64()
+                                    .or_else(|| size.as_str().and_then(|s| s.parse().ok()))
+                            })
+                            .unwrap_or(0.5);
+                        if cache_cluster_size > max_cache_cluster_size {
+                            error_reporter.add_error(
+                                Box::new(ApiGatewayViolation::CacheClusterSize),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "CacheClusterSize"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_apigateway_execution_logging<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::ServerlessApi = &resource.type_ {
+                    if let Some(method_settings) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("MethodSettings"))
+                        .and_then(|settings| settings.as_sequence())
+                    {
+                        let verbose_logging = method_settings.iter().any(|setting| {
+                            setting.get("LoggingLevel").and_then(|l| l.as_str()) == Some("INFO")
+                                && setting.get("DataTraceEnabled").and_then(|d| d.as_bool())
+                                    == Some(true)
+                        });
+                        if verbose_logging {
+                            error_reporter.add_error(
+                                Box::new(ApiGatewayViolation::ExecutionLogging),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "MethodSettings"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_apigateway_access_log_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                let access_log_property = match &resource.type_ {
+                    AWSResourceType::ServerlessApi => "AccessLogSetting",
+                    AWSResourceType::ServerlessHttpApi => "AccessLogSettings",
+                    _ => continue,
+                };
+                if let Some(destination) = resource
+                    .properties
+                    .as_ref()
+                    .and_then(|props| props.get(access_log_property))
+                    .and_then(|setting| setting.get("DestinationArn"))
+                {
+                    if let Some((_, log_group)) = find_log_group(resources, destination) {
+                        if !has_log_retention(log_group) {
+                            error_reporter.add_error(
+                                Box::new(ApiGatewayViolation::AccessLogNoRetention),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", access_log_property])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
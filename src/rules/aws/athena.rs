@@ -0,0 +1,62 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::AthenaViolation;
+
+pub fn check_athena_bytes_scanned_cutoff<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::AthenaWorkGroup = &resource.type_ {
+                    let has_cutoff = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("WorkGroupConfiguration"))
+                        .is_some_and(|config| config.get("BytesScannedCutoffPerQuery").is_some());
+                    if !has_cutoff {
+                        error_reporter.add_error(
+                            Box::new(AthenaViolation::MissingBytesScannedCutoff),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_athena_enforce_configuration<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::AthenaWorkGroup = &resource.type_ {
+                    // EnforceWorkGroupConfiguration defaults to true
+                    let not_enforced = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("WorkGroupConfiguration"))
+                        .and_then(|config| config.get("EnforceWorkGroupConfiguration"))
+                        .and_then(|v| v.as_bool())
+                        == Some(false);
+                    if not_enforced {
+                        error_reporter.add_error(
+                            Box::new(AthenaViolation::ConfigurationNotEnforced),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::Resource;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::schedule::ScheduleExpression;
+use crate::parsers::LineMarker;
+use crate::rules::violations::BackupViolation;
+use serde_yaml::Value;
+
+/// Returns the `BackupPlanRule` entries of a backup plan.
+fn backup_rules(resource: &Resource) -> impl Iterator<Item = &Value> {
+    resource
+        .properties
+        .as_ref()
+        .and_then(|props| props.get("BackupPlan"))
+        .and_then(|plan| plan.get("BackupPlanRule"))
+        .and_then(|rules| rules.as_sequence())
+        .into_iter()
+        .flatten()
+}
+
+fn rule_note(rule: &Value) -> String {
+    format!(
+        "backup rule {}",
+        rule.get("RuleName")
+            .and_then(|v| v.as_str())
+            .unwrap_or("<unnamed>")
+    )
+}
+
+fn lifecycle_days(rule: &Value, name: &str) -> Option<u64> {
+    rule.get("Lifecycle")?.get(name)?.as_u64()
+}
+
+const BACKUP_RULE_PATH: [&str; 3] = ["Properties", "BackupPlan", "BackupPlanRule"];
+
+pub fn check_backup_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_retention_days = rule_context
+            .get(RuleType::BACKUP_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(35);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                match &resource.type_ {
+                    AWSResourceType::BackupPlan => {
+                        for rule in backup_rules(resource) {
+                            if lifecycle_days(rule, "DeleteAfterDays")
+                                .is_some_and(|days| days > max_retention_days)
+                            {
+                                let mut path = vec![key.as_str()];
+                                path.extend(BACKUP_RULE_PATH);
+                                error_reporter.add_error_with_note(
+                                    Box::new(BackupViolation::RetentionPeriod),
+                                    key,
+                                    line_marker.get_resource_span(path).copied(),
+                                    rule_note(rule),
+                                );
+                            }
+                        }
+                    }
+                    // A vault lock rejects backups with a shorter retention
+                    AWSResourceType::BackupVault => {
+                        let min_retention_days = resource
+                            .properties
+                            .as_ref()
+                            .and_then(|props| props.get("LockConfiguration"))
+                            .and_then(|lock| lock.get("MinRetentionDays"))
+                            .and_then(|v| v.as_u64());
+                        if min_retention_days.is_some_and(|days| days > max_retention_days) {
+                            error_reporter.add_error(
+                                Box::new(BackupViolation::RetentionPeriod),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "LockConfiguration"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+pub fn check_backup_cold_storage<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let long_retention_days = rule_context
+            .get(RuleType::BACKUP_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(180);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::BackupPlan = &resource.type_ {
+                    for rule in backup_rules(resource) {
+                        // Backups without DeleteAfterDays are kept forever
+                        let long_retention = lifecycle_days(rule, "DeleteAfterDays")
+                            .is_none_or(|days| days > long_retention_days);
+                        if long_retention
+                            && lifecycle_days(rule, "MoveToColdStorageAfterDays").is_none()
+                        {
+                            let mut path = vec![key.as_str()];
+                            path.extend(BACKUP_RULE_PATH);
+                            error_reporter.add_error_with_note(
+                                Box::new(BackupViolation::MissingColdStorage),
+                                key,
+                                line_marker.get_resource_span(path).copied(),
+                                rule_note(rule),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_backup_frequency<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_runs_per_day = rule_context
+            .get(RuleType::BACKUP_003)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(1);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::BackupPlan = &resource.type_ {
+                    for rule in backup_rules(resource) {
+                        let runs_per_day = rule
+                            .get("ScheduleExpression")
+                            .and_then(|v| v.as_str())
+                            .and_then(ScheduleExpression::parse)
+                            .map(|schedule| schedule.runs_per_day());
+                        if runs_per_day.is_some_and(|runs| runs > max_runs_per_day as f64) {
+                            let mut path = vec![key.as_str()];
+                            path.extend(BACKUP_RULE_PATH);
+                            error_reporter.add_error_with_note(
+                                Box::new(BackupViolation::FrequentBackups),
+                                key,
+                                line_marker.get_resource_span(path).copied(),
+                                rule_note(rule),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_snapshot_deletion_policy<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_size = rule_context
+            .get(RuleType::BACKUP_004)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(100);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                let size_property = match &resource.type_ {
+                    AWSResourceType::RdsDbInstance | AWSResourceType::RdsDbCluster => {
+                        "AllocatedStorage"
+                    }
+                    AWSResourceType::Ec2Volume => "Size",
+                    _ => continue,
+                };
+                if resource
+                    .other
+                    .get("DeletionPolicy")
+                    .and_then(|v| v.as_str())
+                    != Some("Snapshot")
+                {
+                    continue;
+                }
+                // AllocatedStorage is a string on DB instances; Aurora storage is not declared
+                let size = resource
+                    .properties
+                    .as_ref()
+                    .and_then(|props| props.get(size_property))
+                    .and_then(|v| {
+                        v.as_u64()
+                            .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+                    });
+                if let Some(size) = size.filter(|size| *size > max_size) {
+                    error_reporter.add_error_with_note(
+                        Box::new(BackupViolation::SnapshotOnDeletion),
+                        key,
+                        line_marker
+                            .get_resource_span(vec![key, "DeletionPolicy"])
+                            .copied(),
+                        format!("{} GB of storage", size),
+                    );
+                }
+            }
+        }
+    }
+}
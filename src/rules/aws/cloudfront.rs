@@ -0,0 +1,338 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::{get_ref_or_att, Resource};
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::CloudFrontViolation;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+
+/// ID of the managed `CachingDisabled` cache policy.
+const CACHING_DISABLED_POLICY_ID: &str = "4135ea2d-6df8-44a3-9df3-4b5a84be39ad";
+
+const STATIC_PATH_PREFIXES: [&str; 7] = [
+    "static/",
+    "assets/",
+    "images/",
+    "img/",
+    "fonts/",
+    "media/",
+    "_next/static/",
+];
+
+const STATIC_EXTENSIONS: [&str; 14] = [
+    "js", "css", "map", "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico", "woff", "woff2",
+    "ttf",
+];
+
+/// Returns the cache behaviors of a distribution as `(property name, behavior)`, with the
+/// default cache behavior first.
+fn cache_behaviors(distribution_config: &Value) -> Vec<(&'static str, &Value)> {
+    let default_behavior = distribution_config
+        .get("DefaultCacheBehavior")
+        .map(|behavior| ("DefaultCacheBehavior", behavior));
+    let behaviors = distribution_config
+        .get("CacheBehaviors")
+        .and_then(|behaviors| behaviors.as_sequence())
+        .into_iter()
+        .flatten()
+        .map(|behavior| ("CacheBehaviors", behavior));
+    default_behavior.into_iter().chain(behaviors).collect()
+}
+
+/// Whether a path pattern such as `/static/*` or `*.js` only matches static content.
+fn is_static_path(path_pattern: &str) -> bool {
+    let path_pattern = path_pattern.trim_start_matches('/').to_lowercase();
+    STATIC_PATH_PREFIXES
+        .iter()
+        .any(|prefix| path_pattern.starts_with(prefix))
+        || path_pattern
+            .rsplit_once('.')
+            .is_some_and(|(_, extension)| STATIC_EXTENSIONS.contains(&extension))
+}
+
+/// Finds the S3 bucket resource behind a bucket domain name such as `!GetAtt MyBucket.DomainName`
+/// or `my-bucket.s3.amazonaws.com`.
+fn find_bucket<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    domain_name: &Value,
+) -> Option<&'a Resource> {
+    if let Some(logical_id) = get_ref_or_att(domain_name) {
+        return resources
+            .get(logical_id)
+            .filter(|resource| matches!(resource.type_, AWSResourceType::S3Bucket));
+    }
+    let (bucket_name, _) = domain_name.as_str()?.split_once(".s3")?;
+    resources.values().find(|resource| {
+        matches!(resource.type_, AWSResourceType::S3Bucket)
+            && resource
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("BucketName"))
+                .and_then(|name| name.as_str())
+                == Some(bucket_name)
+    })
+}
+
+/// Whether the origin `origin_id` of a distribution serves content from S3.
+fn is_s3_origin(
+    resources: &IndexMap<String, Resource>,
+    distribution_config: &Value,
+    origin_id: &str,
+) -> bool {
+    distribution_config
+        .get("Origins")
+        .and_then(|origins| origins.as_sequence())
+        .into_iter()
+        .flatten()
+        .filter(|origin| origin.get("Id").and_then(|id| id.as_str()) == Some(origin_id))
+        .any(|origin| {
+            origin.get("S3OriginConfig").is_some()
+                || origin.get("DomainName").is_some_and(|domain_name| {
+                    find_bucket(resources, domain_name).is_some()
+                        || domain_name
+                            .as_str()
+                            .is_some_and(|d| d.contains(".s3.") || d.contains(".s3-"))
+                })
+        })
+}
+
+pub fn check_cloudfront_price_class<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        let Some(allowed_price_classes) = rule_context
+            .get(RuleType::CLOUDFRONT_001)
+            .and_then(|rule_type| rule_type.config_detail.get_values())
+        else {
+            return;
+        };
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CloudFrontDistribution = &resource.type_ {
+                    let Some(distribution_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DistributionConfig"))
+                    else {
+                        continue;
+                    };
+                    // Distributions use every edge location unless told otherwise
+                    let price_class = distribution_config
+                        .get("PriceClass")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("PriceClass_All");
+                    if !allowed_price_classes
+                        .iter()
+                        .any(|allowed| allowed == price_class)
+                    {
+                        let span = if distribution_config.get("PriceClass").is_some() {
+                            line_marker.get_resource_span(vec![
+                                key,
+                                "Properties",
+                                "DistributionConfig",
+                                "PriceClass",
+                            ])
+                        } else {
+                            line_marker.get_resource_span(vec![
+                                key,
+                                "Properties",
+                                "DistributionConfig",
+                            ])
+                        };
+                        error_reporter.add_error(
+                            Box::new(CloudFrontViolation::PriceClass),
+                            key,
+                            span.copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudfront_compression<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CloudFrontDistribution = &resource.type_ {
+                    let Some(distribution_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DistributionConfig"))
+                    else {
+                        continue;
+                    };
+                    for (property, behavior) in cache_behaviors(distribution_config) {
+                        // Compress defaults to false
+                        let compress = behavior
+                            .get("Compress")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false);
+                        if !compress {
+                            error_reporter.add_error(
+                                Box::new(CloudFrontViolation::Compression),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "DistributionConfig",
+                                        property,
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudfront_caching_disabled<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CloudFrontDistribution = &resource.type_ {
+                    let Some(distribution_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DistributionConfig"))
+                    else {
+                        continue;
+                    };
+                    for (property, behavior) in cache_behaviors(distribution_config) {
+                        let caching_disabled =
+                            behavior.get("CachePolicyId").and_then(|v| v.as_str())
+                                == Some(CACHING_DISABLED_POLICY_ID);
+                        if !caching_disabled {
+                            continue;
+                        }
+                        // Static content is matched by path, or served by an S3 origin
+                        let static_content = behavior
+                            .get("PathPattern")
+                            .and_then(|v| v.as_str())
+                            .is_some_and(is_static_path)
+                            || behavior
+                                .get("TargetOriginId")
+                                .and_then(|v| v.as_str())
+                                .is_some_and(|origin_id| {
+                                    is_s3_origin(resources, distribution_config, origin_id)
+                                });
+                        if static_content {
+                            error_reporter.add_error(
+                                Box::new(CloudFrontViolation::CachingDisabled),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "DistributionConfig",
+                                        property,
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudfront_realtime_logs<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CloudFrontDistribution = &resource.type_ {
+                    let Some(distribution_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DistributionConfig"))
+                    else {
+                        continue;
+                    };
+                    for (property, behavior) in cache_behaviors(distribution_config) {
+                        if behavior.get("RealtimeLogConfigArn").is_some() {
+                            error_reporter.add_error(
+                                Box::new(CloudFrontViolation::RealtimeLogs),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "DistributionConfig",
+                                        property,
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudfront_log_bucket_lifecycle<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CloudFrontDistribution = &resource.type_ {
+                    let log_bucket = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DistributionConfig"))
+                        .and_then(|config| config.get("Logging"))
+                        .and_then(|logging| logging.get("Bucket"))
+                        .and_then(|bucket| find_bucket(resources, bucket));
+                    // Buckets managed outside the template cannot be checked
+                    let Some(log_bucket) = log_bucket else {
+                        continue;
+                    };
+                    let has_lifecycle = log_bucket
+                        .properties
+                        .as_ref()
+                        .is_some_and(|props| props.contains_key("LifecycleConfiguration"));
+                    if !has_lifecycle {
+                        error_reporter.add_error(
+                            Box::new(CloudFrontViolation::LogBucketLifecycle),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "DistributionConfig",
+                                    "Logging",
+                                ])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
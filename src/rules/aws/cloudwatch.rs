@@ -1,37 +1,142 @@
 use crate::error_reporter::ErrorReporter;
-use crate::parsers::config::{RuleConfig, RuleType};
+use crate::parsers::cfn::{get_ref_or_att, get_sub_template, Resource};
+use crate::parsers::config::{RuleContext, RuleType};
 use crate::parsers::iac::AWSResourceType;
 use crate::parsers::iac::InfratructureTemplate;
 use crate::parsers::LineMarker;
 use crate::rules::violations::CloudWatchViolation;
+use indexmap::IndexMap;
+use marked_yaml::Span;
+use std::collections::HashSet;
+
+/// Whether a log group defines a retention policy. Log groups without one fail CW-002.
+pub(crate) fn has_log_retention(resource: &Resource) -> bool {
+    resource
+        .properties
+        .as_ref()
+        .is_some_and(|properties| properties.contains_key("RetentionInDays"))
+}
+
+/// Finds the log group resource referenced by `value`, either through `!Ref`/`!GetAtt`
+/// or by a literal name or ARN matching the log group's `LogGroupName`.
+pub(crate) fn find_log_group<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    value: &serde_yaml::Value,
+) -> Option<(&'a String, &'a Resource)> {
+    if let Some(logical_id) = get_ref_or_att(value) {
+        return resources
+            .get_key_value(logical_id)
+            .filter(|(_, resource)| matches!(resource.type_, AWSResourceType::CloudWatch));
+    }
+    let log_group_name = value.as_str()?;
+    // Accept log group ARNs as well as plain log group names
+    let log_group_name = log_group_name
+        .split_once(":log-group:")
+        .map_or(log_group_name, |(_, name)| name.trim_end_matches(":*"));
+    resources.iter().find(|(_, resource)| {
+        matches!(resource.type_, AWSResourceType::CloudWatch)
+            && resource
+                .properties
+                .as_ref()
+                .and_then(|p| p.get("LogGroupName"))
+                .and_then(|name| name.as_str())
+                == Some(log_group_name)
+    })
+}
+
+/// Returns the logical ID of the Lambda function whose logs are written to `log_group`.
+/// Lambda writes to `/aws/lambda/<function name>`, so the `LogGroupName` expression is
+/// compared against both `!Ref` of the function and its `FunctionName` expression.
+pub(crate) fn find_lambda_log_group_owner<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    log_group: &Resource,
+) -> Option<&'a String> {
+    let function_name = log_group
+        .properties
+        .as_ref()
+        .and_then(|p| p.get("LogGroupName"))
+        .and_then(get_sub_template)?
+        .strip_prefix("/aws/lambda/")?
+        .to_string();
+    resources
+        .iter()
+        .find(|(key, resource)| {
+            matches!(
+                resource.type_,
+                AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction
+            ) && (function_name == format!("${{{}}}", key)
+                || resource
+                    .properties
+                    .as_ref()
+                    .and_then(|p| p.get("FunctionName"))
+                    .and_then(get_sub_template)
+                    .is_some_and(|name| name == function_name))
+        })
+        .map(|(key, _)| key)
+}
+
+/// Reports a log group violation, naming the Lambda function that owns the log group.
+fn add_log_group_error(
+    resources: &IndexMap<String, Resource>,
+    error_reporter: &mut ErrorReporter,
+    violation: CloudWatchViolation,
+    key: &str,
+    log_group: &Resource,
+    span: Option<Span>,
+) {
+    if let Some(function) = find_lambda_log_group_owner(resources, log_group) {
+        error_reporter.add_error_with_note(
+            Box::new(violation),
+            key,
+            span,
+            format!("log group of Lambda function {}", function),
+        );
+    } else {
+        error_reporter.add_error(Box::new(violation), key, span);
+    }
+}
 
 pub fn check_cloudwatch_log_group_retention<L: LineMarker>(
     infra_template: &InfratructureTemplate,
-    rule_config: &RuleConfig,
+    rule_context: &RuleContext,
     error_reporter: &mut ErrorReporter,
     line_marker: &L,
-    environment: &str,
 ) {
     if let Some(cloudformation) = &infra_template.cloudformation {
         if let Some(resources) = &cloudformation.resources {
             for (key, resource) in resources {
                 if let AWSResourceType::CloudWatch = &resource.type_ {
                     if let Some(properties) = &resource.properties {
-                        if let Some(retention) = properties.get("RetentionInDays") {
-                            if !rule_config.enabled(RuleType::CW_001, environment) {
+                        if !has_log_retention(resource) {
+                            if !rule_context.enabled(RuleType::CW_002) {
                                 continue;
                             }
-                            if let Some(log_retention_days) =
-                                rule_config.get_rule(RuleType::CW_001, environment)
-                            {
+                            add_log_group_error(
+                                resources,
+                                error_reporter,
+                                CloudWatchViolation::NoLogRetention,
+                                key,
+                                resource,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties"])
+                                    .copied(),
+                            );
+                        } else if let Some(retention) = properties.get("RetentionInDays") {
+                            if !rule_context.enabled(RuleType::CW_001) {
+                                continue;
+                            }
+                            if let Some(log_retention_days) = rule_context.get(RuleType::CW_001) {
                                 if let Some(threshold) =
                                     log_retention_days.config_detail.get_threshold_int()
                                 {
                                     // Check if the retention period is longer than the threshold
                                     if retention.as_u64().is_none_or(|v| v > threshold) {
-                                        error_reporter.add_error(
-                                            Box::new(CloudWatchViolation::LogRetentionTooLong),
+                                        add_log_group_error(
+                                            resources,
+                                            error_reporter,
+                                            CloudWatchViolation::LogRetentionTooLong,
                                             key,
+                                            resource,
                                             line_marker
                                                 .get_resource_span(vec![
                                                     key,
@@ -43,17 +148,6 @@ pub fn check_cloudwatch_log_group_retention<L: LineMarker>(
                                     }
                                 }
                             }
-                        } else {
-                            if !rule_config.enabled(RuleType::CW_002, environment) {
-                                continue;
-                            }
-                            error_reporter.add_error(
-                                Box::new(CloudWatchViolation::NoLogRetention),
-                                key,
-                                line_marker
-                                    .get_resource_span(vec![key, "Properties"])
-                                    .copied(),
-                            );
                         }
                     }
                 }
@@ -62,6 +156,29 @@ pub fn check_cloudwatch_log_group_retention<L: LineMarker>(
     }
 }
 
+/// Returns the metric filters and subscription filters reading from the log group `key`.
+/// Log groups using the INFREQUENT_ACCESS class support neither.
+fn infrequent_access_consumers<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    key: &str,
+) -> Vec<&'a String> {
+    resources
+        .iter()
+        .filter(|(_, resource)| {
+            matches!(
+                resource.type_,
+                AWSResourceType::LogsMetricFilter | AWSResourceType::LogsSubscriptionFilter
+            ) && resource
+                .properties
+                .as_ref()
+                .and_then(|props| props.get("LogGroupName"))
+                .and_then(|name| find_log_group(resources, name))
+                .is_some_and(|(log_group_key, _)| log_group_key == key)
+        })
+        .map(|(consumer, _)| consumer)
+        .collect()
+}
+
 pub fn check_cloudwatch_log_group_class<L: LineMarker>(
     infra_template: &InfratructureTemplate,
     error_reporter: &mut ErrorReporter,
@@ -72,23 +189,82 @@ pub fn check_cloudwatch_log_group_class<L: LineMarker>(
             for (key, resource) in resources {
                 if let AWSResourceType::CloudWatch = &resource.type_ {
                     if let Some(properties) = &resource.properties {
-                        if let Some(log_group_class) = properties.get("LogGroupClass") {
-                            if log_group_class.as_str() == Some("STANDARD") {
-                                error_reporter.add_error(
-                                    Box::new(CloudWatchViolation::InfrequentAccessLogGroupClass),
+                        let span = match properties.get("LogGroupClass") {
+                            Some(log_group_class)
+                                if log_group_class.as_str() == Some("STANDARD") =>
+                            {
+                                line_marker.get_resource_span(vec![
                                     key,
-                                    line_marker
-                                        .get_resource_span(vec![key, "Properties", "LogGroupClass"])
-                                        .copied(),
-                                );
+                                    "Properties",
+                                    "LogGroupClass",
+                                ])
                             }
-                        } else {
+                            Some(_) => continue,
+                            None => line_marker.get_resource_span(vec![key, "Properties"]),
+                        };
+                        let consumers = infrequent_access_consumers(resources, key);
+                        if consumers.is_empty() {
                             error_reporter.add_error(
                                 Box::new(CloudWatchViolation::InfrequentAccessLogGroupClass),
                                 key,
-                                line_marker
-                                    .get_resource_span(vec![key, "Properties"])
-                                    .copied(),
+                                span.copied(),
+                            );
+                        } else {
+                            error_reporter.add_error_with_note(
+                                Box::new(
+                                    CloudWatchViolation::InfrequentAccessIncompatibleConsumers,
+                                ),
+                                key,
+                                span.copied(),
+                                format!(
+                                    "consumed by {}",
+                                    consumers
+                                        .iter()
+                                        .map(|consumer| consumer.as_str())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudwatch_alarm_high_resolution<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CloudWatchAlarm = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        // Metric math alarms set the period on each metric instead of the alarm
+                        let metric_periods = properties
+                            .get("Metrics")
+                            .and_then(|metrics| metrics.as_sequence())
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|metric| metric.get("MetricStat")?.get("Period"));
+                        let high_resolution = properties
+                            .get("Period")
+                            .into_iter()
+                            .chain(metric_periods)
+                            .any(|period| period.as_u64().is_some_and(|v| v < 60));
+                        if high_resolution {
+                            let span = if properties.contains_key("Period") {
+                                line_marker.get_resource_span(vec![key, "Properties", "Period"])
+                            } else {
+                                line_marker.get_resource_span(vec![key, "Properties", "Metrics"])
+                            };
+                            error_reporter.add_error(
+                                Box::new(CloudWatchViolation::HighResolutionAlarm),
+                                key,
+                                span.copied(),
                             );
                         }
                     }
@@ -97,3 +273,165 @@ pub fn check_cloudwatch_log_group_class<L: LineMarker>(
         }
     }
 }
+
+pub fn check_cloudwatch_alarms_per_function<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_alarms = rule_context
+            .get(RuleType::CW_005)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(5);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction =
+                    &resource.type_
+                {
+                    let function_name = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|p| p.get("FunctionName"))
+                        .and_then(|name| name.as_str());
+                    // Alarms referencing the function, or naming it in their dimensions
+                    let mut alarms: HashSet<&str> = infra_template
+                        .graph
+                        .dependents(key)
+                        .map(|edge| edge.from.as_str())
+                        .filter(|from| {
+                            resources.get(*from).is_some_and(|r| {
+                                matches!(r.type_, AWSResourceType::CloudWatchAlarm)
+                            })
+                        })
+                        .collect();
+                    if let Some(function_name) = function_name {
+                        alarms.extend(
+                            resources
+                                .iter()
+                                .filter(|(_, alarm)| {
+                                    matches!(alarm.type_, AWSResourceType::CloudWatchAlarm)
+                                        && alarm
+                                            .properties
+                                            .as_ref()
+                                            .and_then(|p| p.get("Dimensions"))
+                                            .and_then(|d| d.as_sequence())
+                                            .is_some_and(|dimensions| {
+                                                dimensions.iter().any(|dimension| {
+                                                    dimension.get("Value").and_then(|v| v.as_str())
+                                                        == Some(function_name)
+                                                })
+                                            })
+                                })
+                                .map(|(alarm_key, _)| alarm_key.as_str()),
+                        );
+                    }
+                    if alarms.len() as u64 > max_alarms {
+                        error_reporter.add_error(
+                            Box::new(CloudWatchViolation::TooManyAlarms),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudwatch_metric_filter_log_group_class<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LogsMetricFilter = &resource.type_ {
+                    let infrequent_access = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("LogGroupName"))
+                        .and_then(|name| find_log_group(resources, name))
+                        .and_then(|(_, log_group)| log_group.properties.as_ref())
+                        .and_then(|props| props.get("LogGroupClass"))
+                        .is_some_and(|class| class.as_str() == Some("INFREQUENT_ACCESS"));
+                    if infrequent_access {
+                        error_reporter.add_error(
+                            Box::new(CloudWatchViolation::MetricFilterOnInfrequentAccess),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "LogGroupName"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudwatch_subscription_filter_pattern<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LogsSubscriptionFilter = &resource.type_ {
+                    // An empty pattern matches every log event
+                    let unfiltered = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("FilterPattern"))
+                        .is_none_or(|pattern| {
+                            pattern.as_str().is_some_and(|p| p.trim().is_empty())
+                        });
+                    if unfiltered {
+                        error_reporter.add_error(
+                            Box::new(CloudWatchViolation::UnfilteredSubscription),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_cloudwatch_dashboard_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_dashboards = rule_context
+            .get(RuleType::CW_008)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(3);
+
+        if let Some(resources) = &cloudformation.resources {
+            // Report every dashboard beyond the threshold
+            for (key, _) in resources
+                .iter()
+                .filter(|(_, resource)| {
+                    matches!(resource.type_, AWSResourceType::CloudWatchDashboard)
+                })
+                .skip(max_dashboards as usize)
+            {
+                error_reporter.add_error(
+                    Box::new(CloudWatchViolation::DashboardFreeTier),
+                    key,
+                    line_marker.get_resource_span(vec![key]).copied(),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::aws::cloudwatch::{find_log_group, has_log_retention};
+use crate::rules::violations::CodeBuildViolation;
+
+const LARGE_COMPUTE_TYPES: [&str; 3] = [
+    "BUILD_GENERAL1_LARGE",
+    "BUILD_GENERAL1_XLARGE",
+    "BUILD_GENERAL1_2XLARGE",
+];
+
+pub fn check_codebuild_compute_type<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CodeBuildProject = &resource.type_ {
+                    let compute_type = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("Environment"))
+                        .and_then(|env| env.get("ComputeType"))
+                        .and_then(|v| v.as_str());
+                    if compute_type.is_some_and(|t| LARGE_COMPUTE_TYPES.contains(&t)) {
+                        error_reporter.add_error(
+                            Box::new(CodeBuildViolation::LargeComputeType),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "Environment",
+                                    "ComputeType",
+                                ])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_codebuild_arm_image<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CodeBuildProject = &resource.type_ {
+                    let Some(environment) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("Environment"))
+                    else {
+                        continue;
+                    };
+                    let x86 =
+                        environment.get("Type").and_then(|v| v.as_str()) == Some("LINUX_CONTAINER");
+                    // Custom images may not be built for ARM
+                    let managed_image = environment
+                        .get("Image")
+                        .and_then(|v| v.as_str())
+                        .is_some_and(|image| image.starts_with("aws/codebuild/"));
+                    if x86 && managed_image {
+                        error_reporter.add_error(
+                            Box::new(CodeBuildViolation::X86Image),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "Environment", "Type"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_codebuild_cache<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CodeBuildProject = &resource.type_ {
+                    let cache_type = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("Cache"))
+                        .and_then(|cache| cache.get("Type"))
+                        .and_then(|v| v.as_str());
+                    if cache_type.is_none_or(|t| t == "NO_CACHE") {
+                        error_reporter.add_error(
+                            Box::new(CodeBuildViolation::MissingCache),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_codebuild_log_group_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::CodeBuildProject = &resource.type_ {
+                    // CloudWatch build logs are enabled unless switched off
+                    let cloudwatch_logs = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("LogsConfig"))
+                        .and_then(|logs| logs.get("CloudWatchLogs"));
+                    if cloudwatch_logs
+                        .and_then(|logs| logs.get("Status"))
+                        .and_then(|v| v.as_str())
+                        == Some("DISABLED")
+                    {
+                        continue;
+                    }
+                    // Without a GroupName, CodeBuild creates /aws/codebuild/<project> without retention
+                    let log_group = cloudwatch_logs
+                        .and_then(|logs| logs.get("GroupName"))
+                        .and_then(|group| find_log_group(resources, group));
+                    if log_group.is_none_or(|(_, log_group)| !has_log_retention(log_group)) {
+                        error_reporter.add_error(
+                            Box::new(CodeBuildViolation::NoLogRetention),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::Resource;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::aws::schedules::scheduled_invocations_per_month;
+use crate::rules::violations::CodePipelineViolation;
+use indexmap::IndexMap;
+
+/// Returns the pipelines of type `pipeline_type` started by schedules in the template, with
+/// their executions per month. Pipelines started by source changes have no known frequency.
+fn scheduled_pipelines<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    pipeline_type: &str,
+) -> Vec<(&'a String, f64)> {
+    resources
+        .iter()
+        .filter(|(_, resource)| {
+            matches!(resource.type_, AWSResourceType::CodePipelinePipeline)
+                && resource
+                    .properties
+                    .as_ref()
+                    .and_then(|props| props.get("PipelineType"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("V1")
+                    == pipeline_type
+        })
+        .map(|(key, _)| (key, scheduled_invocations_per_month(resources, key)))
+        .filter(|(_, executions)| *executions > 0.0)
+        .collect()
+}
+
+pub fn check_codepipeline_frequent_v2<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_executions = rule_context
+            .get(RuleType::CODEPIPELINE_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(30);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, executions) in scheduled_pipelines(resources, "V2") {
+                if executions > max_executions as f64 {
+                    error_reporter.add_error_with_note(
+                        Box::new(CodePipelineViolation::FrequentV2Pipeline),
+                        key,
+                        line_marker.get_resource_span(vec![key]).copied(),
+                        format!("scheduled about {:.0} times per month", executions),
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn check_codepipeline_infrequent_v1<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let min_executions = rule_context
+            .get(RuleType::CODEPIPELINE_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(30);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, executions) in scheduled_pipelines(resources, "V1") {
+                if executions < min_executions as f64 {
+                    error_reporter.add_error_with_note(
+                        Box::new(CodePipelineViolation::InfrequentV1Pipeline),
+                        key,
+                        line_marker.get_resource_span(vec![key]).copied(),
+                        format!("scheduled about {:.0} times per month", executions),
+                    );
+                }
+            }
+        }
+    }
+}
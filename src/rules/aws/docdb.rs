@@ -0,0 +1,84 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::get_ref;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::node_types::instance_family;
+use crate::parsers::LineMarker;
+use crate::rules::violations::DocDbViolation;
+
+pub fn check_docdb_instance_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_instances = rule_context
+            .get(RuleType::DOCDB_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(1);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::DocDbCluster = &resource.type_ {
+                    // Instances are separate resources referring to the cluster
+                    let instance_count = resources
+                        .values()
+                        .filter(|instance| {
+                            matches!(instance.type_, AWSResourceType::DocDbInstance)
+                                && instance
+                                    .properties
+                                    .as_ref()
+                                    .and_then(|props| props.get("DBClusterIdentifier"))
+                                    .and_then(get_ref)
+                                    == Some(key.as_str())
+                        })
+                        .count() as u64;
+                    if instance_count > max_instances {
+                        error_reporter.add_error_with_note(
+                            Box::new(DocDbViolation::TooManyInstances),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                            format!("{} instances", instance_count),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_docdb_graviton<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::DocDbInstance = &resource.type_ {
+                    let family = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DBInstanceClass"))
+                        .and_then(|v| v.as_str())
+                        .and_then(instance_family);
+                    if family
+                        .is_some_and(|family| !rule_context.node_types().docdb.is_graviton(family))
+                    {
+                        error_reporter.add_error(
+                            Box::new(DocDbViolation::NonGraviton),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "DBInstanceClass"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::{get_sub_template, Resource};
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::EcrViolation;
+use indexmap::IndexMap;
+use marked_yaml::Span;
+
+/// Returns the Lambda functions whose `ImageUri` points at the repository `repository_key`.
+fn image_functions<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    repository_key: &str,
+) -> Vec<&'a str> {
+    resources
+        .iter()
+        .filter(|(_, resource)| {
+            let image_uri = match resource.type_ {
+                AWSResourceType::LambdaServerlessFunction => {
+                    resource.properties.as_ref().and_then(|p| p.get("ImageUri"))
+                }
+                AWSResourceType::LambdaFunction => resource
+                    .properties
+                    .as_ref()
+                    .and_then(|p| p.get("Code"))
+                    .and_then(|code| code.get("ImageUri")),
+                _ => None,
+            };
+            image_uri
+                .and_then(get_sub_template)
+                .is_some_and(|template| {
+                    template.contains(&format!("${{{}}}", repository_key))
+                        || template.contains(&format!("${{{}.", repository_key))
+                })
+        })
+        .map(|(key, _)| key.as_str())
+        .collect()
+}
+
+/// Reports `violation` on the repository, naming the functions deployed from it.
+fn add_repository_error(
+    error_reporter: &mut ErrorReporter,
+    resources: &IndexMap<String, Resource>,
+    violation: EcrViolation,
+    key: &str,
+    span: Option<Span>,
+) {
+    let functions = image_functions(resources, key);
+    if functions.is_empty() {
+        error_reporter.add_error(Box::new(violation), key, span);
+    } else {
+        error_reporter.add_error_with_note(
+            Box::new(violation),
+            key,
+            span,
+            format!("images used by Lambda functions {}", functions.join(", ")),
+        );
+    }
+}
+
+/// Whether a lifecycle policy lets untagged images live longer than `max_days`. Rules that
+/// keep a fixed number of untagged images bound their storage and are not reported.
+fn untagged_images_exceed(policy: &serde_json::Value, max_days: u64) -> bool {
+    let untagged_rules: Vec<&serde_json::Value> = policy
+        .get("rules")
+        .and_then(|rules| rules.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|rule| rule.get("selection"))
+        .filter(|selection| {
+            matches!(
+                selection.get("tagStatus").and_then(|s| s.as_str()),
+                Some("untagged") | Some("any")
+            )
+        })
+        .collect();
+    if untagged_rules.iter().any(|selection| {
+        selection.get("countType").and_then(|c| c.as_str()) == Some("imageCountMoreThan")
+    }) {
+        return false;
+    }
+    let expiry_days = untagged_rules
+        .iter()
+        .filter(|selection| {
+            selection.get("countType").and_then(|c| c.as_str()) == Some("sinceImagePushed")
+        })
+        .filter_map(|selection| selection.get("countNumber").and_then(|n| n.as_u64()))
+        .min();
+    expiry_days.is_none_or(|days| days > max_days)
+}
+
+pub fn check_ecr_lifecycle_policy<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcrRepository = &resource.type_ {
+                    let has_lifecycle_policy = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("LifecyclePolicy"))
+                        .is_some_and(|policy| policy.get("LifecyclePolicyText").is_some());
+                    if !has_lifecycle_policy {
+                        add_repository_error(
+                            error_reporter,
+                            resources,
+                            EcrViolation::MissingLifecyclePolicy,
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_ecr_untagged_image_expiry<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_days = rule_context
+            .get(RuleType::ECR_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(14);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcrRepository = &resource.type_ {
+                    // Policies built from parameters cannot be parsed and are skipped
+                    let policy = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("LifecyclePolicy"))
+                        .and_then(|policy| policy.get("LifecyclePolicyText"))
+                        .and_then(get_sub_template)
+                        .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok());
+                    if policy.is_some_and(|policy| untagged_images_exceed(&policy, max_days)) {
+                        add_repository_error(
+                            error_reporter,
+                            resources,
+                            EcrViolation::UntaggedImageExpiry,
+                            key,
+                            line_marker
+                                .get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "LifecyclePolicy",
+                                    "LifecyclePolicyText",
+                                ])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
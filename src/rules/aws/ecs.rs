@@ -0,0 +1,281 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::get_ref;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::aws::cloudwatch::{find_log_group, has_log_retention};
+use crate::rules::violations::EcsViolation;
+
+pub fn check_ecs_task_architecture_arm<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcsTaskDefinition = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        let cpu_architecture = properties
+                            .get("RuntimePlatform")
+                            .and_then(|platform| platform.get("CpuArchitecture"))
+                            .and_then(|arch| arch.as_str());
+                        if cpu_architecture.is_none_or(|arch| !arch.eq_ignore_ascii_case("ARM64")) {
+                            let span = if properties.contains_key("RuntimePlatform") {
+                                line_marker.get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "RuntimePlatform",
+                                ])
+                            } else {
+                                line_marker.get_resource_span(vec![key])
+                            };
+                            error_reporter.add_error(
+                                Box::new(EcsViolation::ARMArchitecture),
+                                key,
+                                span.copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether a capacity provider strategy includes `FARGATE_SPOT`, or `None` when the
+/// strategy does not run on Fargate at all (e.g. EC2 Auto Scaling capacity providers).
+fn uses_fargate_spot(strategy: &serde_yaml::Value) -> Option<bool> {
+    let providers: Vec<&str> = strategy
+        .as_sequence()?
+        .iter()
+        .filter_map(|item| item.get("CapacityProvider").and_then(|p| p.as_str()))
+        .collect();
+    if providers.contains(&"FARGATE_SPOT") {
+        Some(true)
+    } else if providers.contains(&"FARGATE") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+pub fn check_ecs_service_fargate_spot<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcsService = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        let missing_fargate_spot =
+                            if let Some(strategy) = properties.get("CapacityProviderStrategy") {
+                                uses_fargate_spot(strategy) == Some(false)
+                            } else if let Some(launch_type) = properties.get("LaunchType") {
+                                launch_type.as_str() == Some("FARGATE")
+                            } else {
+                                // Without a launch type or strategy the cluster default strategy applies
+                                properties
+                                    .get("Cluster")
+                                    .and_then(get_ref)
+                                    .and_then(|cluster| resources.get(cluster))
+                                    .and_then(|cluster| cluster.properties.as_ref())
+                                    .and_then(|p| p.get("DefaultCapacityProviderStrategy"))
+                                    .and_then(uses_fargate_spot)
+                                    == Some(false)
+                            };
+
+                        if missing_fargate_spot {
+                            error_reporter.add_error(
+                                Box::new(EcsViolation::FargateSpot),
+                                key,
+                                line_marker.get_resource_span(vec![key]).copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parses a task CPU value such as `1024` or `"1 vCPU"` into CPU units.
+fn parse_cpu_units(value: &serde_yaml::Value) -> Option<u64> {
+    if let Some(units) = value.as_u64() {
+        return Some(units);
+    }
+    let value = value.as_str()?.trim();
+    if let Some(vcpu) = value
+        .strip_suffix("vCPU")
+        .or_else(|| value.strip_suffix("vcpu"))
+    {
+        return vcpu.trim().parse::<f64>().ok().map(|v| (v * 1024.0) as u64);
+    }
+    value.parse().ok()
+}
+
+/// Parses a task memory value such as `2048` or `"2 GB"` into MiB.
+fn parse_memory_mib(value: &serde_yaml::Value) -> Option<u64> {
+    if let Some(mib) = value.as_u64() {
+        return Some(mib);
+    }
+    let value = value.as_str()?.trim();
+    if let Some(gb) = value
+        .strip_suffix("GB")
+        .or_else(|| value.strip_suffix("gb"))
+    {
+        return gb.trim().parse::<f64>().ok().map(|v| (v * 1024.0) as u64);
+    }
+    value.parse().ok()
+}
+
+pub fn check_ecs_task_size<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_cpu_units = rule_context
+            .get(RuleType::ECS_003)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(2048);
+        // Allow up to 4 GB of memory per vCPU of the configured size
+        let max_memory_mib = max_cpu_units * 4;
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcsTaskDefinition = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        let cpu = properties.get("Cpu").and_then(parse_cpu_units);
+                        let memory = properties.get("Memory").and_then(parse_memory_mib);
+                        if cpu.is_some_and(|v| v > max_cpu_units) {
+                            error_reporter.add_error(
+                                Box::new(EcsViolation::OversizedTask),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "Cpu"])
+                                    .copied(),
+                            );
+                        } else if memory.is_some_and(|v| v > max_memory_mib) {
+                            error_reporter.add_error(
+                                Box::new(EcsViolation::OversizedTask),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "Memory"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_ecs_container_insights<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcsCluster = &resource.type_ {
+                    if let Some(cluster_settings) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ClusterSettings"))
+                    {
+                        let container_insights_enabled =
+                            cluster_settings.as_sequence().is_some_and(|settings| {
+                                settings.iter().any(|setting| {
+                                    setting.get("Name").and_then(|n| n.as_str())
+                                        == Some("containerInsights")
+                                        && setting
+                                            .get("Value")
+                                            .and_then(|v| v.as_str())
+                                            .is_some_and(|v| v != "disabled")
+                                })
+                            });
+                        if container_insights_enabled {
+                            error_reporter.add_error(
+                                Box::new(EcsViolation::ContainerInsights),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "ClusterSettings"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_ecs_awslogs_log_group_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EcsTaskDefinition = &resource.type_ {
+                    if let Some(container_definitions) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ContainerDefinitions"))
+                        .and_then(|defs| defs.as_sequence())
+                    {
+                        let containers: Vec<&str> = container_definitions
+                            .iter()
+                            .filter(|container| {
+                                let Some(log_configuration) = container.get("LogConfiguration")
+                                else {
+                                    return false;
+                                };
+                                if log_configuration.get("LogDriver").and_then(|d| d.as_str())
+                                    != Some("awslogs")
+                                {
+                                    return false;
+                                }
+                                let log_group = log_configuration
+                                    .get("Options")
+                                    .and_then(|options| options.get("awslogs-group"))
+                                    .and_then(|group| find_log_group(resources, group));
+                                log_group.is_none_or(|(_, log_group)| !has_log_retention(log_group))
+                            })
+                            .map(|container| {
+                                container
+                                    .get("Name")
+                                    .and_then(|name| name.as_str())
+                                    .unwrap_or("unnamed")
+                            })
+                            .collect();
+                        // Reported once per task definition, naming the containers
+                        if !containers.is_empty() {
+                            error_reporter.add_error_with_note(
+                                Box::new(EcsViolation::AwslogsNoLogRetention),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "ContainerDefinitions",
+                                    ])
+                                    .copied(),
+                                format!("awslogs containers {}", containers.join(", ")),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
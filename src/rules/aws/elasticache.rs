@@ -0,0 +1,130 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::Resource;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::node_types::instance_family;
+use crate::parsers::LineMarker;
+use crate::rules::violations::ElastiCacheViolation;
+
+/// Returns the `CacheNodeType` of cache clusters and replication groups.
+fn cache_node_type(resource: &Resource) -> Option<&str> {
+    match resource.type_ {
+        AWSResourceType::ElastiCacheCacheCluster | AWSResourceType::ElastiCacheReplicationGroup => {
+            resource.properties.as_ref()?.get("CacheNodeType")?.as_str()
+        }
+        _ => None,
+    }
+}
+
+pub fn check_elasticache_previous_generation<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let Some(node_type) = cache_node_type(resource) {
+                    if let Some(replacement) = instance_family(node_type).and_then(|family| {
+                        rule_context
+                            .node_types()
+                            .elasticache
+                            .previous_generation(family)
+                    }) {
+                        error_reporter.add_error_with_note(
+                            Box::new(ElastiCacheViolation::PreviousGeneration),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "CacheNodeType"])
+                                .copied(),
+                            format!(
+                                "consider the {} family instead of {}",
+                                replacement, node_type
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_elasticache_graviton<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            let node_types = &rule_context.node_types().elasticache;
+            for (key, resource) in resources {
+                if let Some(family) = cache_node_type(resource).and_then(instance_family) {
+                    // Previous generation families are reported by ELASTICACHE-001
+                    if node_types.previous_generation(family).is_none()
+                        && !node_types.is_graviton(family)
+                    {
+                        error_reporter.add_error(
+                            Box::new(ElastiCacheViolation::NonGraviton),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "CacheNodeType"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_elasticache_replica_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_replicas = rule_context
+            .get(RuleType::ELASTICACHE_003)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(1);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::ElastiCacheReplicationGroup = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        // Replicas per shard, either set directly or implied by the cluster count
+                        let replicas = properties
+                            .get("ReplicasPerNodeGroup")
+                            .and_then(|v| v.as_u64())
+                            .or_else(|| {
+                                properties
+                                    .get("NumCacheClusters")
+                                    .and_then(|v| v.as_u64())
+                                    .map(|clusters| clusters.saturating_sub(1))
+                            })
+                            .or_else(|| {
+                                properties
+                                    .get("NodeGroupConfiguration")?
+                                    .as_sequence()?
+                                    .iter()
+                                    .filter_map(|group| group.get("ReplicaCount")?.as_u64())
+                                    .max()
+                            });
+                        if replicas.is_some_and(|v| v > max_replicas) {
+                            error_reporter.add_error(
+                                Box::new(ElastiCacheViolation::TooManyReplicas),
+                                key,
+                                line_marker.get_resource_span(vec![key]).copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
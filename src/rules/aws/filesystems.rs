@@ -0,0 +1,219 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::{get_ref, get_ref_or_att, Resource};
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::FileSystemViolation;
+use indexmap::IndexMap;
+use marked_yaml::Span;
+
+/// Properties of each FSx deployment that hold `AutomaticBackupRetentionDays`.
+const FSX_CONFIGURATIONS: [&str; 4] = [
+    "WindowsConfiguration",
+    "LustreConfiguration",
+    "OntapConfiguration",
+    "OpenZFSConfiguration",
+];
+
+/// Returns the Lambda functions mounting the EFS file system `file_system_key` through an
+/// access point in `FileSystemConfigs`.
+fn mounting_functions<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    file_system_key: &str,
+) -> Vec<&'a str> {
+    resources
+        .iter()
+        .filter(|(_, resource)| {
+            matches!(
+                resource.type_,
+                AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction
+            )
+        })
+        .filter(|(_, resource)| {
+            resource
+                .properties
+                .as_ref()
+                .and_then(|props| props.get("FileSystemConfigs"))
+                .and_then(|configs| configs.as_sequence())
+                .into_iter()
+                .flatten()
+                .filter_map(|config| config.get("Arn").and_then(get_ref_or_att))
+                .filter_map(|access_point| resources.get(access_point))
+                .filter(|access_point| {
+                    matches!(access_point.type_, AWSResourceType::EfsAccessPoint)
+                })
+                .any(|access_point| {
+                    access_point
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("FileSystemId"))
+                        .and_then(get_ref)
+                        == Some(file_system_key)
+                })
+        })
+        .map(|(key, _)| key.as_str())
+        .collect()
+}
+
+/// Reports `violation` on the file system, naming the functions that mount it.
+fn add_file_system_error(
+    error_reporter: &mut ErrorReporter,
+    resources: &IndexMap<String, Resource>,
+    violation: FileSystemViolation,
+    key: &str,
+    span: Option<Span>,
+) {
+    let functions = mounting_functions(resources, key);
+    if functions.is_empty() {
+        error_reporter.add_error(Box::new(violation), key, span);
+    } else {
+        error_reporter.add_error_with_note(
+            Box::new(violation),
+            key,
+            span,
+            format!("mounted by Lambda functions {}", functions.join(", ")),
+        );
+    }
+}
+
+pub fn check_efs_lifecycle_policy<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EfsFileSystem = &resource.type_ {
+                    // TransitionToPrimaryStorageClass alone never moves files out of Standard
+                    let has_transition = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("LifecyclePolicies"))
+                        .and_then(|policies| policies.as_sequence())
+                        .into_iter()
+                        .flatten()
+                        .any(|policy| {
+                            policy.get("TransitionToIA").is_some()
+                                || policy.get("TransitionToArchive").is_some()
+                        });
+                    if !has_transition {
+                        add_file_system_error(
+                            error_reporter,
+                            resources,
+                            FileSystemViolation::EfsLifecyclePolicy,
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_efs_provisioned_throughput<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EfsFileSystem = &resource.type_ {
+                    let throughput_mode = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ThroughputMode"))
+                        .and_then(|v| v.as_str());
+                    if throughput_mode == Some("provisioned") {
+                        add_file_system_error(
+                            error_reporter,
+                            resources,
+                            FileSystemViolation::EfsProvisionedThroughput,
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "ThroughputMode"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_efs_one_zone<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EfsFileSystem = &resource.type_ {
+                    // One Zone file systems are pinned to an Availability Zone
+                    let one_zone = resource
+                        .properties
+                        .as_ref()
+                        .is_some_and(|props| props.contains_key("AvailabilityZoneName"));
+                    if !one_zone {
+                        add_file_system_error(
+                            error_reporter,
+                            resources,
+                            FileSystemViolation::EfsOneZone,
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_fsx_backup_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_retention_days = rule_context
+            .get(RuleType::FS_004)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(7);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::FsxFileSystem = &resource.type_ {
+                    let Some(properties) = &resource.properties else {
+                        continue;
+                    };
+                    for configuration in FSX_CONFIGURATIONS {
+                        let retention_days = properties
+                            .get(configuration)
+                            .and_then(|c| c.get("AutomaticBackupRetentionDays"))
+                            .and_then(|v| v.as_u64());
+                        if retention_days.is_some_and(|days| days > max_retention_days) {
+                            error_reporter.add_error(
+                                Box::new(FileSystemViolation::FsxBackupRetention),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        configuration,
+                                        "AutomaticBackupRetentionDays",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::schedule::ScheduleExpression;
+use crate::parsers::LineMarker;
+use crate::rules::violations::GlueViolation;
+
+/// Returns the number of DPUs of a `G.<n>X` worker type, e.g. 2 for `G.2X`.
+fn worker_dpus(worker_type: &str) -> Option<f64> {
+    worker_type
+        .strip_prefix("G.")?
+        .strip_suffix('X')?
+        .parse()
+        .ok()
+}
+
+pub fn check_glue_worker_type<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::GlueJob = &resource.type_ {
+                    let large_workers = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("WorkerType"))
+                        .and_then(|v| v.as_str())
+                        .and_then(worker_dpus)
+                        .is_some_and(|dpus| dpus >= 2.0);
+                    if large_workers {
+                        error_reporter.add_error(
+                            Box::new(GlueViolation::LargeWorkerType),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "WorkerType"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_glue_number_of_workers<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_workers = rule_context
+            .get(RuleType::GLUE_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(10);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::GlueJob = &resource.type_ {
+                    let number_of_workers = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("NumberOfWorkers"))
+                        .and_then(|v| v.as_u64());
+                    if number_of_workers.is_some_and(|workers| workers > max_workers) {
+                        error_reporter.add_error(
+                            Box::new(GlueViolation::TooManyWorkers),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "NumberOfWorkers"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_glue_execution_class<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::GlueJob = &resource.type_ {
+                    let Some(properties) = &resource.properties else {
+                        continue;
+                    };
+                    // Flexible execution is only available to Spark ETL jobs
+                    let spark_job = properties
+                        .get("Command")
+                        .and_then(|command| command.get("Name"))
+                        .and_then(|v| v.as_str())
+                        == Some("glueetl");
+                    let flex = properties
+                        .get("ExecutionClass")
+                        .and_then(|v| v.as_str())
+                        .is_some_and(|class| class.eq_ignore_ascii_case("FLEX"));
+                    if spark_job && !flex {
+                        error_reporter.add_error(
+                            Box::new(GlueViolation::StandardExecutionClass),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_glue_crawler_schedule<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_runs_per_day = rule_context
+            .get(RuleType::GLUE_004)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(4);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::GlueCrawler = &resource.type_ {
+                    let Some(schedule) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("Schedule"))
+                        .and_then(|schedule| schedule.get("ScheduleExpression"))
+                        .and_then(|v| v.as_str())
+                        .and_then(ScheduleExpression::parse)
+                    else {
+                        continue;
+                    };
+                    let runs_per_day = schedule.runs_per_day();
+                    if runs_per_day > max_runs_per_day as f64 {
+                        error_reporter.add_error_with_note(
+                            Box::new(GlueViolation::FrequentCrawler),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "Schedule",
+                                    "ScheduleExpression",
+                                ])
+                                .copied(),
+                            format!("runs about {:.0} times per day", runs_per_day),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
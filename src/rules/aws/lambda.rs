@@ -1,63 +1,52 @@
 use crate::error_reporter::ErrorReporter;
-use crate::parsers::config::{RuleConfig, RuleType};
+use crate::parsers::cfn::{get_att, get_ref_or_att, Resource};
+use crate::parsers::config::{RuleContext, RuleType, RuleTypeConfigDetail, TagMatch, TagPolicy};
 use crate::parsers::iac::AWSResourceType;
 use crate::parsers::iac::InfratructureTemplate;
 use crate::parsers::LineMarker;
+use crate::rules::aws::cloudwatch::find_lambda_log_group_owner;
+use crate::rules::aws::schedules::scheduled_invocations_per_month;
+use crate::rules::aws::tagging::{resource_tags, tag_policy_findings};
 use crate::rules::violations::LambdaViolation;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+use std::collections::HashMap;
 
 pub fn check_lambda_missing_tag<L: LineMarker>(
     infra_template: &InfratructureTemplate,
-    rule_config: &RuleConfig,
+    rule_context: &RuleContext,
     error_reporter: &mut ErrorReporter,
     line_marker: &L,
 ) {
     if let Some(cloudformation) = &infra_template.cloudformation {
+        // A list of tags requires at least one of them, a tag policy is applied as written
+        let policy = match rule_context
+            .get(RuleType::LAMBDA_003)
+            .map(|rule_type| &rule_type.config_detail)
+        {
+            Some(RuleTypeConfigDetail::TagPolicy { policy }) => policy.clone(),
+            Some(RuleTypeConfigDetail::Values { values }) => {
+                TagPolicy::from_keys(values, TagMatch::Any)
+            }
+            _ => TagPolicy::from_keys(&[], TagMatch::Any),
+        };
+
         if let Some(resources) = &cloudformation.resources {
             for (key, resource) in resources {
                 if let AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction =
                     &resource.type_
                 {
-                    if let Some(properties) = &resource.properties {
-                        if let Some(tags) = properties.get("Tags") {
-                            if let Some(rule_type) = rule_config.rules.get(&RuleType::LAMBDA_003) {
-                                if let Some(target_tags) = rule_type.config_detail.get_values() {
-                                    // Check if at least one tag is defined in the resource
-                                    let tag_exists = target_tags.iter().any(|target_tag| {
-                                        tags.as_sequence().is_some_and(|seq| {
-                                            seq.iter().any(|tag_mapping| {
-                                                tag_mapping
-                                                    .as_mapping()
-                                                    .is_some_and(|m| m.contains_key(target_tag))
-                                            })
-                                        })
-                                    });
-
-                                    if !tag_exists {
-                                        error_reporter.add_error(
-                                            Box::new(LambdaViolation::MissingTag),
-                                            key,
-                                            line_marker
-                                                .get_resource_span(vec![key, "Properties", "Tags"])
-                                                .copied(),
-                                        );
-                                    }
-                                }
-                            } else {
-                                error_reporter.add_error(
-                                    Box::new(LambdaViolation::MissingTag),
-                                    key,
-                                    line_marker
-                                        .get_resource_span(vec![key, "Properties", "Tags"])
-                                        .copied(),
-                                );
-                            }
-                        } else {
-                            error_reporter.add_error(
-                                Box::new(LambdaViolation::MissingTag),
-                                key,
-                                line_marker.get_resource_span(vec![key]).copied(),
-                            )
-                        };
+                    let tags = resource_tags(cloudformation, resource);
+                    if let Some(note) = tag_policy_findings(&policy, &tags) {
+                        error_reporter.add_error_with_note(
+                            Box::new(LambdaViolation::MissingTag),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "Tags"])
+                                .or_else(|| line_marker.get_resource_span(vec![key]))
+                                .copied(),
+                            note,
+                        );
                     }
                 }
             }
@@ -115,6 +104,14 @@ pub fn check_lambda_missing_log_group<L: LineMarker>(
                 if let AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction =
                     &resource.type_
                 {
+                    // A log group named after the function is used instead of an implicit one
+                    let has_linked_log_group = resources.values().any(|log_group| {
+                        matches!(log_group.type_, AWSResourceType::CloudWatch)
+                            && find_lambda_log_group_owner(resources, log_group) == Some(key)
+                    });
+                    if has_linked_log_group {
+                        continue;
+                    }
                     if let Some(properties) = &resource.properties {
                         if let Some(logging_config) = properties.get("LoggingConfig") {
                             if !logging_config
@@ -145,15 +142,14 @@ pub fn check_lambda_missing_log_group<L: LineMarker>(
 
 pub fn check_lambda_maxmimum_retry_attempts<L: LineMarker>(
     infra_template: &InfratructureTemplate,
-    rule_config: &RuleConfig,
+    rule_context: &RuleContext,
     error_reporter: &mut ErrorReporter,
     line_marker: &L,
 ) {
     if let Some(cloudformation) = &infra_template.cloudformation {
         // Fetch threshold from the rule configuration
-        let max_retry_attempts_config = rule_config
-            .rules
-            .get(&RuleType::LAMBDA_004)
+        let max_retry_attempts_config = rule_context
+            .get(RuleType::LAMBDA_004)
             .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
             .unwrap_or(0);
 
@@ -195,10 +191,9 @@ pub fn check_lambda_maxmimum_retry_attempts<L: LineMarker>(
 
 pub fn check_lambda_powertools_environment_variables<L: LineMarker>(
     infra_template: &InfratructureTemplate,
-    rule_config: &RuleConfig,
+    rule_context: &RuleContext,
     error_reporter: &mut ErrorReporter,
     line_marker: &L,
-    environment: &str,
 ) {
     if let Some(cloudformation) = &infra_template.cloudformation {
         if let Some(resources) = &cloudformation.resources {
@@ -212,9 +207,7 @@ pub fn check_lambda_powertools_environment_variables<L: LineMarker>(
                         .and_then(|props| props.get("Environment"))
                         .and_then(|env| env.get("Variables"))
                     {
-                        if let Some(rule_type) =
-                            rule_config.get_rule(RuleType::LAMBDA_005, environment)
-                        {
+                        if let Some(rule_type) = rule_context.get(RuleType::LAMBDA_005) {
                             if rule_type.enabled {
                                 if let Some(target_log_level) = rule_type.config_detail.get_value()
                                 {
@@ -242,9 +235,7 @@ pub fn check_lambda_powertools_environment_variables<L: LineMarker>(
                             }
                         }
 
-                        if let Some(rule_type) =
-                            rule_config.get_rule(RuleType::LAMBDA_006, environment)
-                        {
+                        if let Some(rule_type) = rule_context.get(RuleType::LAMBDA_006) {
                             if rule_type.enabled {
                                 if let Some(powertools_logger_log_event) =
                                     variables.get("POWERTOOLS_LOGGER_LOG_EVENT")
@@ -267,17 +258,12 @@ pub fn check_lambda_powertools_environment_variables<L: LineMarker>(
                             }
                         }
 
-                        if let Some(rule_type) =
-                            rule_config.get_rule(RuleType::LAMBDA_007, environment)
-                        {
+                        if let Some(rule_type) = rule_context.get(RuleType::LAMBDA_007) {
                             if rule_type.enabled {
                                 // Fetch threshold from the rule configuration
-                                let powertools_logger_sample_rate_config = rule_config
-                                    .rules
-                                    .get(&RuleType::LAMBDA_007)
-                                    .and_then(|rule_type| {
-                                        rule_type.config_detail.get_threshold_float()
-                                    })
+                                let powertools_logger_sample_rate_config = rule_type
+                                    .config_detail
+                                    .get_threshold_number()
                                     .unwrap_or(1.0);
                                 if let Some(powertools_logger_sample_rate) =
                                     variables.get("POWERTOOLS_LOGGER_SAMPLE_RATE")
@@ -307,3 +293,469 @@ pub fn check_lambda_powertools_environment_variables<L: LineMarker>(
         }
     }
 }
+
+pub fn check_lambda_memory_size<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_memory_size = rule_context
+            .get(RuleType::LAMBDA_008)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(1024);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction =
+                    &resource.type_
+                {
+                    if let Some(memory_size) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("MemorySize"))
+                    {
+                        if memory_size.as_u64().is_some_and(|v| v > max_memory_size) {
+                            let span = line_marker
+                                .get_resource_span(vec![key, "Properties", "MemorySize"])
+                                .copied();
+                            // Scheduled invocations are a known share of the function's cost
+                            let invocations = scheduled_invocations_per_month(resources, key);
+                            if invocations > 0.0 {
+                                error_reporter.add_error_with_note(
+                                    Box::new(LambdaViolation::MemorySize),
+                                    key,
+                                    span,
+                                    format!("scheduled about {:.0} times per month", invocations),
+                                );
+                            } else {
+                                error_reporter.add_error(
+                                    Box::new(LambdaViolation::MemorySize),
+                                    key,
+                                    span,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_lambda_api_timeout<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_timeout = rule_context
+            .get(RuleType::LAMBDA_009)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(600);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LambdaServerlessFunction = &resource.type_ {
+                    let api_triggered = resource
+                        .events()
+                        .iter()
+                        .any(|(_, event_type, _)| matches!(*event_type, "Api" | "HttpApi"));
+                    if !api_triggered {
+                        continue;
+                    }
+                    if let Some(timeout) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("Timeout"))
+                    {
+                        if timeout.as_u64().is_some_and(|v| v > max_timeout) {
+                            error_reporter.add_error(
+                                Box::new(LambdaViolation::ApiTimeout),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "Timeout"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_lambda_ephemeral_storage<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_ephemeral_storage = rule_context
+            .get(RuleType::LAMBDA_010)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(512);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction =
+                    &resource.type_
+                {
+                    if let Some(size) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("EphemeralStorage"))
+                        .and_then(|storage| storage.get("Size"))
+                    {
+                        if size.as_u64().is_some_and(|v| v > max_ephemeral_storage) {
+                            error_reporter.add_error(
+                                Box::new(LambdaViolation::EphemeralStorage),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "EphemeralStorage"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_lambda_provisioned_concurrency<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::LambdaServerlessFunction
+                | AWSResourceType::LambdaAlias
+                | AWSResourceType::LambdaVersion = &resource.type_
+                {
+                    if let Some(provisioned_executions) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ProvisionedConcurrencyConfig"))
+                        .and_then(|config| config.get("ProvisionedConcurrentExecutions"))
+                    {
+                        if provisioned_executions.as_u64().is_none_or(|v| v > 0) {
+                            error_reporter.add_error(
+                                Box::new(LambdaViolation::ProvisionedConcurrency),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "ProvisionedConcurrencyConfig",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The kind of source polled by a Lambda event source mapping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum EventSourceKind {
+    Sqs,
+    /// Kinesis and DynamoDB streams
+    Stream,
+    /// Amazon MSK and self-managed Apache Kafka
+    Kafka,
+    Other,
+}
+
+impl EventSourceKind {
+    /// Maps a SAM event type to the kind of source it polls, or `None` for push-based events.
+    fn from_sam_event(event_type: &str) -> Option<Self> {
+        match event_type {
+            "SQS" => Some(Self::Sqs),
+            "Kinesis" | "DynamoDB" => Some(Self::Stream),
+            "MSK" | "SelfManagedKafka" => Some(Self::Kafka),
+            "MQ" | "DocumentDB" => Some(Self::Other),
+            _ => None,
+        }
+    }
+
+    fn default_batch_size(&self) -> u64 {
+        match self {
+            Self::Sqs => 10,
+            _ => 100,
+        }
+    }
+}
+
+enum EventSourceProperties<'a> {
+    SamEvent(Option<&'a Value>),
+    Mapping(Option<&'a HashMap<String, Value>>),
+}
+
+/// A Lambda event source mapping, declared either as a SAM `Events` entry on the function
+/// or as an `AWS::Lambda::EventSourceMapping` resource.
+pub(crate) struct EventSource<'a> {
+    /// Logical ID of the resource declaring the mapping
+    pub key: &'a String,
+    /// Resource path of the mapping declaration, used for line spans
+    pub path: Vec<&'a str>,
+    pub kind: EventSourceKind,
+    /// The `Queue`, `Stream` or `EventSourceArn` value of the mapping
+    pub source: Option<&'a Value>,
+    properties: EventSourceProperties<'a>,
+}
+
+impl<'a> EventSource<'a> {
+    pub fn get(&self, name: &str) -> Option<&'a Value> {
+        match self.properties {
+            EventSourceProperties::SamEvent(properties) => properties?.get(name),
+            EventSourceProperties::Mapping(properties) => properties?.get(name),
+        }
+    }
+
+    /// Returns the SQS queue resource polled by the mapping, resolved through `!Ref`/`!GetAtt`.
+    pub fn queue(&self, resources: &'a IndexMap<String, Resource>) -> Option<&'a Resource> {
+        self.source
+            .and_then(get_ref_or_att)
+            .and_then(|logical_id| resources.get(logical_id))
+            .filter(|resource| matches!(resource.type_, AWSResourceType::SqsQueue))
+    }
+}
+
+/// Infers the source kind of an `AWS::Lambda::EventSourceMapping` from its `EventSourceArn`.
+fn event_source_arn_kind(resources: &IndexMap<String, Resource>, value: &Value) -> EventSourceKind {
+    if let Some((logical_id, attribute)) = get_att(value) {
+        return match resources.get(logical_id).map(|resource| &resource.type_) {
+            Some(AWSResourceType::SqsQueue) => EventSourceKind::Sqs,
+            Some(AWSResourceType::KinesisStream) => EventSourceKind::Stream,
+            Some(AWSResourceType::MskCluster) => EventSourceKind::Kafka,
+            // DynamoDB tables expose their stream through the StreamArn attribute
+            _ if attribute == "StreamArn" => EventSourceKind::Stream,
+            _ => EventSourceKind::Other,
+        };
+    }
+    match value.as_str() {
+        Some(arn) if arn.starts_with("arn:aws:sqs:") => EventSourceKind::Sqs,
+        Some(arn)
+            if arn.starts_with("arn:aws:kinesis:") || arn.starts_with("arn:aws:dynamodb:") =>
+        {
+            EventSourceKind::Stream
+        }
+        Some(arn) if arn.starts_with("arn:aws:kafka:") => EventSourceKind::Kafka,
+        _ => EventSourceKind::Other,
+    }
+}
+
+/// Collects every event source mapping declared in the template.
+pub(crate) fn event_sources(resources: &IndexMap<String, Resource>) -> Vec<EventSource<'_>> {
+    let mut event_sources = Vec::new();
+    for (key, resource) in resources {
+        match &resource.type_ {
+            AWSResourceType::LambdaServerlessFunction => {
+                for (name, event_type, properties) in resource.events() {
+                    if let Some(kind) = EventSourceKind::from_sam_event(event_type) {
+                        event_sources.push(EventSource {
+                            key,
+                            path: vec![key, "Properties", "Events", name],
+                            kind,
+                            source: properties
+                                .and_then(|p| p.get("Queue").or_else(|| p.get("Stream"))),
+                            properties: EventSourceProperties::SamEvent(properties),
+                        });
+                    }
+                }
+            }
+            AWSResourceType::LambdaEventSourceMapping => {
+                let properties = resource.properties.as_ref();
+                let source = properties.and_then(|p| p.get("EventSourceArn"));
+                let kind = if properties.is_some_and(|p| p.contains_key("SelfManagedEventSource")) {
+                    EventSourceKind::Kafka
+                } else {
+                    source.map_or(EventSourceKind::Other, |arn| {
+                        event_source_arn_kind(resources, arn)
+                    })
+                };
+                event_sources.push(EventSource {
+                    key,
+                    path: vec![key, "Properties"],
+                    kind,
+                    source,
+                    properties: EventSourceProperties::Mapping(properties),
+                });
+            }
+            _ => {}
+        }
+    }
+    event_sources
+}
+
+pub fn check_lambda_event_source_batch_size<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let min_batch_size = rule_context
+            .get(RuleType::LAMBDA_012)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(10);
+
+        if let Some(resources) = &cloudformation.resources {
+            for event_source in event_sources(resources) {
+                // FIFO queues do not support a batching window
+                let fifo_queue = event_source
+                    .queue(resources)
+                    .and_then(|queue| queue.properties.as_ref())
+                    .and_then(|props| props.get("FifoQueue"))
+                    .and_then(|fifo| fifo.as_bool())
+                    .unwrap_or(false);
+                if fifo_queue {
+                    continue;
+                }
+                let batch_size = event_source
+                    .get("BatchSize")
+                    .and_then(|size| size.as_u64())
+                    .unwrap_or(event_source.kind.default_batch_size());
+                let batching_window = event_source
+                    .get("MaximumBatchingWindowInSeconds")
+                    .and_then(|window| window.as_u64())
+                    .unwrap_or(0);
+                if batch_size < min_batch_size && batching_window == 0 {
+                    error_reporter.add_error(
+                        Box::new(LambdaViolation::SmallBatchSize),
+                        event_source.key,
+                        line_marker.get_resource_span(event_source.path).copied(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn check_lambda_event_source_filter_criteria<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for event_source in event_sources(resources) {
+                if matches!(
+                    event_source.kind,
+                    EventSourceKind::Stream | EventSourceKind::Kafka
+                ) && event_source.get("FilterCriteria").is_none()
+                {
+                    error_reporter.add_error(
+                        Box::new(LambdaViolation::MissingFilterCriteria),
+                        event_source.key,
+                        line_marker.get_resource_span(event_source.path).copied(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn check_lambda_event_source_stream_retries<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for event_source in event_sources(resources) {
+                if event_source.kind != EventSourceKind::Stream {
+                    continue;
+                }
+                // Both settings default to -1, retrying until the record expires from the stream
+                let unbounded = |name: &str| {
+                    event_source
+                        .get(name)
+                        .and_then(|value| value.as_i64())
+                        .is_none_or(|value| value < 0)
+                };
+                if !unbounded("MaximumRetryAttempts") || !unbounded("MaximumRecordAgeInSeconds") {
+                    continue;
+                }
+                // Bisecting only splits the batch, the bad record is still retried until it expires
+                let bisect = event_source
+                    .get("BisectBatchOnFunctionError")
+                    .and_then(|value| value.as_bool())
+                    == Some(true);
+                let span = line_marker.get_resource_span(event_source.path).copied();
+                if bisect {
+                    error_reporter.add_error(
+                        Box::new(LambdaViolation::UnboundedStreamRetries),
+                        event_source.key,
+                        span,
+                    );
+                } else {
+                    error_reporter.add_error_with_note(
+                        Box::new(LambdaViolation::UnboundedStreamRetries),
+                        event_source.key,
+                        span,
+                        "BisectBatchOnFunctionError is not enabled either".to_string(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn check_lambda_sqs_retry_amplification<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_receive_count_config = rule_context
+            .get(RuleType::LAMBDA_015)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(10);
+
+        if let Some(resources) = &cloudformation.resources {
+            for event_source in event_sources(resources) {
+                if event_source.kind != EventSourceKind::Sqs {
+                    continue;
+                }
+                let max_receive_count = event_source
+                    .queue(resources)
+                    .and_then(|queue| queue.properties.as_ref())
+                    .and_then(|props| props.get("RedrivePolicy"))
+                    .and_then(|policy| policy.get("maxReceiveCount"))
+                    .and_then(|count| {
+                        count
+                            .as_u64()
+                            .or_else(|| count.as_str().and_then(|c| c.parse().ok()))
+                    });
+                let Some(max_receive_count) = max_receive_count else {
+                    continue;
+                };
+                // Event source mappings invoke synchronously, so the function's asynchronous
+                // retry settings don't apply and each receive is a single invocation
+                if max_receive_count > max_receive_count_config {
+                    error_reporter.add_error(
+                        Box::new(LambdaViolation::SqsRetryAmplification),
+                        event_source.key,
+                        line_marker.get_resource_span(event_source.path).copied(),
+                    );
+                }
+            }
+        }
+    }
+}
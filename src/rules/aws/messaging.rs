@@ -0,0 +1,242 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::get_ref_or_att;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::aws::lambda::{event_sources, EventSourceKind};
+use crate::rules::violations::MessagingViolation;
+use std::collections::HashSet;
+
+pub fn check_sqs_short_polling<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            // Lambda event source mappings always long poll, whatever the queue setting
+            let lambda_consumed_queues: HashSet<&str> = event_sources(resources)
+                .into_iter()
+                .filter(|event_source| event_source.kind == EventSourceKind::Sqs)
+                .filter_map(|event_source| event_source.source.and_then(get_ref_or_att))
+                .collect();
+
+            for (key, resource) in resources {
+                if let AWSResourceType::SqsQueue = &resource.type_ {
+                    if lambda_consumed_queues.contains(key.as_str()) {
+                        continue;
+                    }
+                    let wait_time = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ReceiveMessageWaitTimeSeconds"));
+                    if let Some(wait_time) = wait_time {
+                        if wait_time.as_u64() == Some(0) {
+                            error_reporter.add_error(
+                                Box::new(MessagingViolation::ShortPolling),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "ReceiveMessageWaitTimeSeconds",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    } else {
+                        error_reporter.add_error(
+                            Box::new(MessagingViolation::ShortPolling),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_sqs_message_retention_period<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_retention_seconds = rule_context
+            .get(RuleType::MSG_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(345600);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SqsQueue = &resource.type_ {
+                    if let Some(retention) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("MessageRetentionPeriod"))
+                    {
+                        if retention
+                            .as_u64()
+                            .is_some_and(|v| v > max_retention_seconds)
+                        {
+                            error_reporter.add_error(
+                                Box::new(MessagingViolation::MessageRetentionPeriod),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "MessageRetentionPeriod",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_sqs_fifo_queue<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Queues listed in the rule configuration require ordering
+        let ordered_queues = rule_context
+            .get(RuleType::MSG_003)
+            .and_then(|rule_type| rule_type.config_detail.get_values())
+            .cloned()
+            .unwrap_or_default();
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SqsQueue = &resource.type_ {
+                    let is_fifo = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("FifoQueue"))
+                        .and_then(|fifo| fifo.as_bool())
+                        == Some(true);
+                    if is_fifo && !ordered_queues.contains(key) {
+                        error_reporter.add_error(
+                            Box::new(MessagingViolation::FifoQueue),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "FifoQueue"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_eventbridge_archive_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::EventsArchive = &resource.type_ {
+                    // A retention of 0 days keeps events indefinitely
+                    let retention_days = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("RetentionDays"))
+                        .and_then(|days| days.as_u64())
+                        .unwrap_or(0);
+                    if retention_days == 0 {
+                        error_reporter.add_error(
+                            Box::new(MessagingViolation::ArchiveRetention),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_sns_lambda_filter_policy<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                match &resource.type_ {
+                    AWSResourceType::SnsSubscription => {
+                        if let Some(properties) = &resource.properties {
+                            if properties.get("Protocol").and_then(|p| p.as_str()) == Some("lambda")
+                                && !properties.contains_key("FilterPolicy")
+                            {
+                                error_reporter.add_error(
+                                    Box::new(MessagingViolation::SnsFilterPolicy),
+                                    key,
+                                    line_marker.get_resource_span(vec![key]).copied(),
+                                );
+                            }
+                        }
+                    }
+                    AWSResourceType::SnsTopic => {
+                        // Inline subscriptions cannot define a filter policy
+                        let inline_lambda_subscription = resource
+                            .properties
+                            .as_ref()
+                            .and_then(|props| props.get("Subscription"))
+                            .and_then(|subscriptions| subscriptions.as_sequence())
+                            .is_some_and(|subscriptions| {
+                                subscriptions.iter().any(|subscription| {
+                                    subscription.get("Protocol").and_then(|p| p.as_str())
+                                        == Some("lambda")
+                                })
+                            });
+                        if inline_lambda_subscription {
+                            error_reporter.add_error(
+                                Box::new(MessagingViolation::SnsFilterPolicy),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "Subscription"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                    AWSResourceType::LambdaServerlessFunction => {
+                        for (event_name, event_type, properties) in resource.events() {
+                            if event_type == "SNS"
+                                && properties.is_none_or(|p| p.get("FilterPolicy").is_none())
+                            {
+                                error_reporter.add_error(
+                                    Box::new(MessagingViolation::SnsFilterPolicy),
+                                    key,
+                                    line_marker
+                                        .get_resource_span(vec![
+                                            key,
+                                            "Properties",
+                                            "Events",
+                                            event_name,
+                                        ])
+                                        .copied(),
+                                );
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,23 @@
+pub mod apigateway;
+pub mod athena;
+pub mod backup;
+pub mod cloudfront;
 pub mod cloudwatch;
+pub mod codebuild;
+pub mod codepipeline;
+pub mod docdb;
+pub mod ecr;
+pub mod ecs;
+pub mod elasticache;
+pub mod filesystems;
+pub mod glue;
 pub mod lambda;
+pub mod messaging;
+pub mod opensearch;
+pub mod redshift;
+pub mod sagemaker;
+pub mod schedules;
+pub mod secrets;
+pub mod stepfunctions;
+pub mod streams;
+pub mod tagging;
@@ -0,0 +1,256 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::node_types::instance_family;
+use crate::parsers::LineMarker;
+use crate::rules::violations::OpenSearchViolation;
+
+/// Instance type properties of `ClusterConfig`, UltraWarm nodes excluded.
+const INSTANCE_TYPE_PROPERTIES: [&str; 2] = ["InstanceType", "DedicatedMasterType"];
+
+pub fn check_opensearch_previous_generation<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::OpenSearchDomain = &resource.type_ {
+                    let Some(cluster_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ClusterConfig"))
+                    else {
+                        continue;
+                    };
+                    for property in INSTANCE_TYPE_PROPERTIES {
+                        let Some(instance_type) =
+                            cluster_config.get(property).and_then(|t| t.as_str())
+                        else {
+                            continue;
+                        };
+                        if let Some(replacement) =
+                            instance_family(instance_type).and_then(|family| {
+                                rule_context
+                                    .node_types()
+                                    .opensearch
+                                    .previous_generation(family)
+                            })
+                        {
+                            error_reporter.add_error_with_note(
+                                Box::new(OpenSearchViolation::PreviousGeneration),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "ClusterConfig",
+                                        property,
+                                    ])
+                                    .copied(),
+                                format!(
+                                    "consider the {} family instead of {}",
+                                    replacement, instance_type
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_opensearch_graviton<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            let node_types = &rule_context.node_types().opensearch;
+            for (key, resource) in resources {
+                if let AWSResourceType::OpenSearchDomain = &resource.type_ {
+                    let Some(cluster_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ClusterConfig"))
+                    else {
+                        continue;
+                    };
+                    for property in INSTANCE_TYPE_PROPERTIES {
+                        let Some(family) = cluster_config
+                            .get(property)
+                            .and_then(|t| t.as_str())
+                            .and_then(instance_family)
+                        else {
+                            continue;
+                        };
+                        // Previous generation families are reported by OPENSEARCH-001
+                        if node_types.previous_generation(family).is_none()
+                            && !node_types.is_graviton(family)
+                        {
+                            error_reporter.add_error(
+                                Box::new(OpenSearchViolation::NonGraviton),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "ClusterConfig",
+                                        property,
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_opensearch_dedicated_master<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let min_data_nodes = rule_context
+            .get(RuleType::OPENSEARCH_003)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(3);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::OpenSearchDomain = &resource.type_ {
+                    if let Some(cluster_config) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ClusterConfig"))
+                    {
+                        let dedicated_master = cluster_config
+                            .get("DedicatedMasterEnabled")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false);
+                        let data_nodes = cluster_config
+                            .get("InstanceCount")
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or(1);
+                        if dedicated_master && data_nodes < min_data_nodes {
+                            error_reporter.add_error(
+                                Box::new(OpenSearchViolation::DedicatedMasterOnSmallDomain),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "ClusterConfig",
+                                        "DedicatedMasterEnabled",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_opensearch_gp2_volume<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::OpenSearchDomain = &resource.type_ {
+                    let volume_type = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("EBSOptions"))
+                        .and_then(|ebs| ebs.get("VolumeType"))
+                        .and_then(|v| v.as_str());
+                    if volume_type == Some("gp2") {
+                        error_reporter.add_error(
+                            Box::new(OpenSearchViolation::Gp2Volume),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "EBSOptions",
+                                    "VolumeType",
+                                ])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_opensearch_warm_storage<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_hot_storage_gb = rule_context
+            .get(RuleType::OPENSEARCH_005)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(1024);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::OpenSearchDomain = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        let cluster_config = properties.get("ClusterConfig");
+                        let enabled = |value: Option<&serde_yaml::Value>| {
+                            value.and_then(|v| v.as_bool()).unwrap_or(false)
+                        };
+                        let warm_enabled =
+                            enabled(cluster_config.and_then(|c| c.get("WarmEnabled")));
+                        let cold_enabled = enabled(
+                            cluster_config
+                                .and_then(|c| c.get("ColdStorageOptions"))
+                                .and_then(|cold| cold.get("Enabled")),
+                        );
+                        if warm_enabled || cold_enabled {
+                            continue;
+                        }
+                        // Retention is not declared in templates, so the hot storage size stands in for it
+                        let data_nodes = cluster_config
+                            .and_then(|c| c.get("InstanceCount"))
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or(1);
+                        let volume_size = properties
+                            .get("EBSOptions")
+                            .and_then(|ebs| ebs.get("VolumeSize"))
+                            .and_then(|v| v.as_u64());
+                        if volume_size.is_some_and(|size| size * data_nodes >= max_hot_storage_gb) {
+                            error_reporter.add_error(
+                                Box::new(OpenSearchViolation::NoWarmStorage),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "EBSOptions"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
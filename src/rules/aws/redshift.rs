@@ -0,0 +1,113 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::RedshiftViolation;
+
+pub fn check_redshift_node_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_nodes = rule_context
+            .get(RuleType::REDSHIFT_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(2);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::RedshiftCluster = &resource.type_ {
+                    let number_of_nodes = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("NumberOfNodes"))
+                        .and_then(|v| v.as_u64());
+                    if number_of_nodes.is_some_and(|nodes| nodes > max_nodes) {
+                        error_reporter.add_error(
+                            Box::new(RedshiftViolation::TooManyNodes),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "NumberOfNodes"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_redshift_serverless_base_capacity<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_base_capacity = rule_context
+            .get(RuleType::REDSHIFT_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(32);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::RedshiftServerlessWorkgroup = &resource.type_ {
+                    // Workgroups default to a base capacity of 128 RPUs
+                    let base_capacity = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("BaseCapacity"))
+                        .map_or(Some(128), |v| v.as_u64());
+                    if base_capacity.is_some_and(|capacity| capacity > max_base_capacity) {
+                        error_reporter.add_error(
+                            Box::new(RedshiftViolation::BaseCapacity),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "BaseCapacity"])
+                                .or_else(|| line_marker.get_resource_span(vec![key]))
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_redshift_manual_snapshot_retention<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::RedshiftCluster = &resource.type_ {
+                    let retention_period = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ManualSnapshotRetentionPeriod"))
+                        .and_then(|v| v.as_i64());
+                    if retention_period == Some(-1) {
+                        error_reporter.add_error(
+                            Box::new(RedshiftViolation::IndefiniteSnapshotRetention),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![
+                                    key,
+                                    "Properties",
+                                    "ManualSnapshotRetentionPeriod",
+                                ])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
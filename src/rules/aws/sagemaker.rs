@@ -0,0 +1,214 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::Resource;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::SageMakerViolation;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+
+/// Instance family prefixes with GPUs or machine learning accelerators.
+const ACCELERATED_FAMILIES: [&str; 5] = ["p", "g", "inf", "trn", "dl"];
+
+/// Settings of the domain apps that support idle shutdown.
+const IDLE_SHUTDOWN_APPS: [&str; 2] = ["JupyterLabAppSettings", "CodeEditorAppSettings"];
+
+/// Whether an instance type such as `ml.g5.xlarge` has GPUs or accelerators.
+fn is_accelerated(instance_type: &str) -> bool {
+    instance_type
+        .strip_prefix("ml.")
+        .unwrap_or(instance_type)
+        .split('.')
+        .next()
+        .is_some_and(|family| {
+            ACCELERATED_FAMILIES.iter().any(|prefix| {
+                family
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+            })
+        })
+}
+
+fn production_variants(resource: &Resource) -> impl Iterator<Item = &Value> {
+    resource
+        .properties
+        .as_ref()
+        .and_then(|props| props.get("ProductionVariants"))
+        .and_then(|variants| variants.as_sequence())
+        .into_iter()
+        .flatten()
+}
+
+/// Whether an endpoint using the endpoint configuration `config_key` has a scalable target.
+fn has_scalable_target(
+    infra_template: &InfratructureTemplate,
+    resources: &IndexMap<String, Resource>,
+    config_key: &str,
+) -> bool {
+    let is_type = |logical_id: &str, matches: fn(&AWSResourceType) -> bool| {
+        resources
+            .get(logical_id)
+            .is_some_and(|resource| matches(&resource.type_))
+    };
+    infra_template
+        .graph
+        .dependents(config_key)
+        .filter(|edge| {
+            is_type(&edge.from, |t| {
+                matches!(t, AWSResourceType::SageMakerEndpoint)
+            })
+        })
+        .any(|endpoint| {
+            infra_template.graph.dependents(&endpoint.from).any(|edge| {
+                is_type(&edge.from, |t| {
+                    matches!(t, AWSResourceType::ApplicationAutoScalingScalableTarget)
+                })
+            })
+        })
+}
+
+pub fn check_sagemaker_serverless_inference<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SageMakerEndpointConfig = &resource.type_ {
+                    // Asynchronous inference has no serverless option
+                    let asynchronous = resource
+                        .properties
+                        .as_ref()
+                        .is_some_and(|props| props.contains_key("AsyncInferenceConfig"));
+                    // Serverless inference does not support GPUs
+                    let cpu_instances = production_variants(resource).any(|variant| {
+                        variant.get("ServerlessConfig").is_none()
+                            && variant
+                                .get("InstanceType")
+                                .and_then(|v| v.as_str())
+                                .is_some_and(|instance_type| !is_accelerated(instance_type))
+                    });
+                    if !asynchronous && cpu_instances {
+                        error_reporter.add_error(
+                            Box::new(SageMakerViolation::RealTimeEndpoint),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "ProductionVariants"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_sagemaker_notebook_auto_stop<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SageMakerNotebookInstance = &resource.type_ {
+                    let Some(properties) = &resource.properties else {
+                        continue;
+                    };
+                    let gpu = properties
+                        .get("InstanceType")
+                        .and_then(|v| v.as_str())
+                        .is_some_and(is_accelerated);
+                    if gpu && !properties.contains_key("LifecycleConfigName") {
+                        error_reporter.add_error(
+                            Box::new(SageMakerViolation::GpuNotebookWithoutAutoStop),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "InstanceType"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_sagemaker_endpoint_autoscaling<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_instances = rule_context
+            .get(RuleType::SAGEMAKER_003)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(1);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SageMakerEndpointConfig = &resource.type_ {
+                    let instance_count: u64 = production_variants(resource)
+                        .filter(|variant| variant.get("InstanceType").is_some())
+                        .map(|variant| {
+                            variant
+                                .get("InitialInstanceCount")
+                                .and_then(|v| v.as_u64())
+                                .unwrap_or(1)
+                        })
+                        .sum();
+                    if instance_count > max_instances
+                        && !has_scalable_target(infra_template, resources, key)
+                    {
+                        error_reporter.add_error(
+                            Box::new(SageMakerViolation::UnscaledEndpoint),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "ProductionVariants"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_sagemaker_domain_idle_shutdown<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SageMakerDomain = &resource.type_ {
+                    let default_user_settings = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("DefaultUserSettings"));
+                    let idle_shutdown = IDLE_SHUTDOWN_APPS.iter().any(|app| {
+                        default_user_settings
+                            .and_then(|settings| settings.get(app))
+                            .and_then(|app| app.get("AppLifecycleManagement"))
+                            .and_then(|management| management.get("IdleSettings"))
+                            .and_then(|idle| idle.get("LifecycleManagement"))
+                            .and_then(|v| v.as_str())
+                            == Some("ENABLED")
+                    });
+                    if !idle_shutdown {
+                        error_reporter.add_error(
+                            Box::new(SageMakerViolation::DomainIdleShutdown),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
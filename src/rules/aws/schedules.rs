@@ -0,0 +1,166 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::Resource;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::graph::references;
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::schedule::ScheduleExpression;
+use crate::parsers::LineMarker;
+use crate::rules::violations::ScheduleViolation;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+
+/// A schedule declared as an `AWS::Events::Rule`, an `AWS::Scheduler::Schedule` or a SAM
+/// `Schedule`/`ScheduleV2` event of a function or state machine.
+pub(crate) struct Schedule<'a> {
+    /// Logical ID of the resource declaring the schedule
+    pub key: &'a String,
+    /// Resource path of the schedule expression, used for line spans
+    pub path: Vec<&'a str>,
+    pub expression: ScheduleExpression,
+    /// Logical IDs of the targets declared in the template
+    pub targets: Vec<&'a str>,
+}
+
+/// Whether a schedule is switched off through `State: DISABLED` or `Enabled: false`.
+/// `property` looks up a property of the schedule by name.
+fn is_disabled<'a>(property: impl Fn(&str) -> Option<&'a Value>) -> bool {
+    property("State").and_then(|v| v.as_str()) == Some("DISABLED")
+        || property("Enabled").and_then(|v| v.as_bool()) == Some(false)
+}
+
+/// Resolves a target `Arn` to the logical ID of a resource in the template, through
+/// `!Ref`/`!GetAtt` or a `!Sub` ARN such as `arn:aws:codepipeline:...:${MyPipeline}`.
+fn target_logical_id<'a>(
+    resources: &'a IndexMap<String, Resource>,
+    arn: &Value,
+) -> Option<&'a str> {
+    references(arn)
+        .iter()
+        .find_map(|logical_id| resources.get_key_value(logical_id))
+        .map(|(key, _)| key.as_str())
+}
+
+/// Collects every enabled schedule with a parseable expression declared in the template.
+pub(crate) fn schedules(resources: &IndexMap<String, Resource>) -> Vec<Schedule<'_>> {
+    let mut schedules = Vec::new();
+    for (key, resource) in resources {
+        let properties = resource.properties.as_ref();
+        match &resource.type_ {
+            AWSResourceType::EventsRule | AWSResourceType::SchedulerSchedule => {
+                let Some(properties) = properties else {
+                    continue;
+                };
+                if is_disabled(|name| properties.get(name)) {
+                    continue;
+                }
+                let Some(expression) = properties
+                    .get("ScheduleExpression")
+                    .and_then(|v| v.as_str())
+                    .and_then(ScheduleExpression::parse)
+                else {
+                    continue;
+                };
+                // Rules declare a list of targets, schedules a single one
+                let targets = match properties.get("Targets").and_then(|t| t.as_sequence()) {
+                    Some(targets) => targets.iter().collect(),
+                    None => properties.get("Target").into_iter().collect::<Vec<_>>(),
+                };
+                schedules.push(Schedule {
+                    key,
+                    path: vec![key, "Properties", "ScheduleExpression"],
+                    expression,
+                    targets: targets
+                        .into_iter()
+                        .filter_map(|target| {
+                            target
+                                .get("Arn")
+                                .and_then(|arn| target_logical_id(resources, arn))
+                        })
+                        .collect(),
+                });
+            }
+            AWSResourceType::LambdaServerlessFunction | AWSResourceType::ServerlessStateMachine => {
+                for (name, event_type, properties) in resource.events() {
+                    let expression_key = match event_type {
+                        "Schedule" => "Schedule",
+                        "ScheduleV2" => "ScheduleExpression",
+                        _ => continue,
+                    };
+                    if is_disabled(|name| properties.and_then(|props| props.get(name))) {
+                        continue;
+                    }
+                    let Some(expression) = properties
+                        .and_then(|props| props.get(expression_key))
+                        .and_then(|v| v.as_str())
+                        .and_then(ScheduleExpression::parse)
+                    else {
+                        continue;
+                    };
+                    schedules.push(Schedule {
+                        key,
+                        path: vec![
+                            key,
+                            "Properties",
+                            "Events",
+                            name,
+                            "Properties",
+                            expression_key,
+                        ],
+                        expression,
+                        targets: vec![key.as_str()],
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    schedules
+}
+
+/// Returns how many times the schedules in the template invoke `function_key` in an average month.
+pub(crate) fn scheduled_invocations_per_month(
+    resources: &IndexMap<String, Resource>,
+    function_key: &str,
+) -> f64 {
+    schedules(resources)
+        .iter()
+        .filter(|schedule| schedule.targets.contains(&function_key))
+        .map(|schedule| schedule.expression.runs_per_month())
+        .sum()
+}
+
+pub fn check_schedule_frequency<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_runs_per_day = rule_context
+            .get(RuleType::SCHEDULE_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(288);
+
+        if let Some(resources) = &cloudformation.resources {
+            for schedule in schedules(resources) {
+                if schedule.expression.runs_per_day() > max_runs_per_day as f64 {
+                    let mut note = format!(
+                        "about {:.0} invocations per month",
+                        schedule.expression.runs_per_month()
+                    );
+                    if !schedule.targets.is_empty() {
+                        note.push_str(&format!(" of {}", schedule.targets.join(", ")));
+                    }
+                    error_reporter.add_error_with_note(
+                        Box::new(ScheduleViolation::FrequentSchedule),
+                        schedule.key,
+                        line_marker.get_resource_span(schedule.path).copied(),
+                        note,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::get_ref;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::SecretsViolation;
+use std::collections::BTreeSet;
+
+/// Largest value in bytes that fits a Standard tier SSM parameter.
+const STANDARD_PARAMETER_MAX_SIZE: usize = 4096;
+
+pub fn check_secrets_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_secrets = rule_context
+            .get(RuleType::SECRET_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(5);
+
+        if let Some(resources) = &cloudformation.resources {
+            // Report every secret beyond the threshold
+            for (key, _) in resources
+                .iter()
+                .filter(|(_, resource)| {
+                    matches!(resource.type_, AWSResourceType::SecretsManagerSecret)
+                })
+                .skip(max_secrets as usize)
+            {
+                error_reporter.add_error(
+                    Box::new(SecretsViolation::TooManySecrets),
+                    key,
+                    line_marker.get_resource_span(vec![key]).copied(),
+                );
+            }
+        }
+    }
+}
+
+pub fn check_kms_key_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_keys = rule_context
+            .get(RuleType::SECRET_002)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(2);
+
+        if let Some(resources) = &cloudformation.resources {
+            // Report every key beyond the threshold
+            for (key, _) in resources
+                .iter()
+                .filter(|(_, resource)| matches!(resource.type_, AWSResourceType::KmsKey))
+                .skip(max_keys as usize)
+            {
+                error_reporter.add_error(
+                    Box::new(SecretsViolation::TooManyKmsKeys),
+                    key,
+                    line_marker.get_resource_span(vec![key]).copied(),
+                );
+            }
+        }
+    }
+}
+
+pub fn check_secrets_rotation<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            let rotated_secrets: Vec<&str> = resources
+                .values()
+                .filter(|resource| {
+                    matches!(
+                        resource.type_,
+                        AWSResourceType::SecretsManagerRotationSchedule
+                    )
+                })
+                .filter_map(|resource| resource.properties.as_ref()?.get("SecretId"))
+                .filter_map(get_ref)
+                .collect();
+            for (key, resource) in resources {
+                if let AWSResourceType::SecretsManagerSecret = &resource.type_ {
+                    // Generated passwords are credentials even when they are not rotated
+                    let generated = resource
+                        .properties
+                        .as_ref()
+                        .is_some_and(|props| props.contains_key("GenerateSecretString"));
+                    if !generated && !rotated_secrets.contains(&key.as_str()) {
+                        error_reporter.add_error(
+                            Box::new(SecretsViolation::NonRotatingSecret),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_kms_key_single_use<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::KmsKey = &resource.type_ {
+                    // Aliases only name the key
+                    let users: BTreeSet<&str> = infra_template
+                        .graph
+                        .dependents(key)
+                        .map(|edge| edge.from.as_str())
+                        .filter(|from| {
+                            !resources
+                                .get(*from)
+                                .is_some_and(|r| matches!(r.type_, AWSResourceType::KmsAlias))
+                        })
+                        .collect();
+                    // Keys without users in the template may be used from elsewhere
+                    if users.len() == 1 {
+                        error_reporter.add_error_with_note(
+                            Box::new(SecretsViolation::SingleUseKmsKey),
+                            key,
+                            line_marker.get_resource_span(vec![key]).copied(),
+                            format!(
+                                "only used by {}",
+                                users.into_iter().next().unwrap_or_default()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_ssm_advanced_parameter<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::SsmParameter = &resource.type_ {
+                    let Some(properties) = &resource.properties else {
+                        continue;
+                    };
+                    let advanced =
+                        properties.get("Tier").and_then(|v| v.as_str()) == Some("Advanced");
+                    // Values resolved at deploy time have an unknown size
+                    let value_size = properties
+                        .get("Value")
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.len());
+                    if advanced
+                        && value_size.is_some_and(|size| size <= STANDARD_PARAMETER_MAX_SIZE)
+                    {
+                        error_reporter.add_error(
+                            Box::new(SecretsViolation::AdvancedParameter),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "Tier"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::StepFunctionsViolation;
+
+/// SAM event source types that can start a workflow at a high request rate.
+const HIGH_VOLUME_EVENT_TYPES: [&str; 4] = ["Api", "HttpApi", "EventBridgeRule", "CloudWatchEvent"];
+
+/// Returns the property name used by `AWS::StepFunctions::StateMachine` or by
+/// `AWS::Serverless::StateMachine`, as SAM renames several of them.
+fn property_name<'a>(
+    resource_type: &AWSResourceType,
+    cloudformation_name: &'a str,
+    serverless_name: &'a str,
+) -> Option<&'a str> {
+    match resource_type {
+        AWSResourceType::StepFunctionsStateMachine => Some(cloudformation_name),
+        AWSResourceType::ServerlessStateMachine => Some(serverless_name),
+        _ => None,
+    }
+}
+
+pub fn check_stepfunctions_express_workflow<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                let Some(type_property) =
+                    property_name(&resource.type_, "StateMachineType", "Type")
+                else {
+                    continue;
+                };
+                // Workflows are STANDARD unless stated otherwise
+                let is_standard = resource
+                    .properties
+                    .as_ref()
+                    .and_then(|props| props.get(type_property))
+                    .and_then(|t| t.as_str())
+                    .is_none_or(|t| t == "STANDARD");
+                let high_volume_source = resource
+                    .events()
+                    .iter()
+                    .any(|(_, event_type, _)| HIGH_VOLUME_EVENT_TYPES.contains(event_type))
+                    // EventBridge rules targeting the state machine
+                    || infra_template.graph.dependents(key).any(|edge| {
+                        resources
+                            .get(&edge.from)
+                            .is_some_and(|r| matches!(r.type_, AWSResourceType::EventsRule))
+                    });
+                if is_standard && high_volume_source {
+                    error_reporter.add_error(
+                        Box::new(StepFunctionsViolation::ExpressWorkflow),
+                        key,
+                        line_marker.get_resource_span(vec![key]).copied(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn check_stepfunctions_logging_level<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let Some(logging_property) =
+                    property_name(&resource.type_, "LoggingConfiguration", "Logging")
+                {
+                    if let Some(logging) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get(logging_property))
+                    {
+                        if logging.get("Level").and_then(|l| l.as_str()) == Some("ALL")
+                            && logging
+                                .get("IncludeExecutionData")
+                                .and_then(|i| i.as_bool())
+                                == Some(true)
+                        {
+                            error_reporter.add_error(
+                                Box::new(StepFunctionsViolation::LoggingExecutionData),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", logging_property])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_stepfunctions_tracing<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let Some(tracing_property) =
+                    property_name(&resource.type_, "TracingConfiguration", "Tracing")
+                {
+                    if let Some(tracing) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get(tracing_property))
+                    {
+                        if tracing.get("Enabled").and_then(|e| e.as_bool()) == Some(true) {
+                            error_reporter.add_error(
+                                Box::new(StepFunctionsViolation::Tracing),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", tracing_property])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::config::{RuleContext, RuleType};
+use crate::parsers::iac::AWSResourceType;
+use crate::parsers::iac::InfratructureTemplate;
+use crate::parsers::LineMarker;
+use crate::rules::violations::StreamViolation;
+use std::collections::HashMap;
+
+/// Firehose's own default buffer size. Smaller buffers produce smaller S3 objects.
+const FIREHOSE_DEFAULT_BUFFER_SIZE_MB: u64 = 5;
+
+/// Returns the Kinesis stream capacity mode, which is PROVISIONED unless stated otherwise.
+fn stream_mode(resource_properties: Option<&HashMap<String, serde_yaml::Value>>) -> &str {
+    resource_properties
+        .and_then(|props| props.get("StreamModeDetails"))
+        .and_then(|details| details.get("StreamMode"))
+        .and_then(|mode| mode.as_str())
+        .unwrap_or("PROVISIONED")
+}
+
+pub fn check_stream_provisioned_shard_count<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_shard_count = rule_context
+            .get(RuleType::STREAM_001)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(10);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::KinesisStream = &resource.type_ {
+                    if stream_mode(resource.properties.as_ref()) != "PROVISIONED" {
+                        continue;
+                    }
+                    if let Some(shard_count) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("ShardCount"))
+                    {
+                        if shard_count.as_u64().is_some_and(|v| v > max_shard_count) {
+                            error_reporter.add_error(
+                                Box::new(StreamViolation::ProvisionedShardCount),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![key, "Properties", "ShardCount"])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Meant for non-production environments, where streams are assumed to carry little traffic.
+pub fn check_stream_on_demand<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::KinesisStream = &resource.type_ {
+                    if stream_mode(resource.properties.as_ref()) == "ON_DEMAND" {
+                        error_reporter.add_error(
+                            Box::new(StreamViolation::OnDemandStream),
+                            key,
+                            line_marker
+                                .get_resource_span(vec![key, "Properties", "StreamModeDetails"])
+                                .copied(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_stream_retention_period<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let max_retention_hours = rule_context
+            .get(RuleType::STREAM_003)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(24);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::KinesisStream = &resource.type_ {
+                    if let Some(retention) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("RetentionPeriodHours"))
+                    {
+                        if retention.as_u64().is_none_or(|v| v > max_retention_hours) {
+                            error_reporter.add_error(
+                                Box::new(StreamViolation::ExtendedRetention),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "RetentionPeriodHours",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_stream_enhanced_monitoring<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::MskCluster = &resource.type_ {
+                    if let Some(enhanced_monitoring) = resource
+                        .properties
+                        .as_ref()
+                        .and_then(|props| props.get("EnhancedMonitoring"))
+                    {
+                        if enhanced_monitoring.as_str() != Some("DEFAULT") {
+                            error_reporter.add_error(
+                                Box::new(StreamViolation::EnhancedMonitoring),
+                                key,
+                                line_marker
+                                    .get_resource_span(vec![
+                                        key,
+                                        "Properties",
+                                        "EnhancedMonitoring",
+                                    ])
+                                    .copied(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn check_stream_firehose_buffering<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        // Fetch threshold from the rule configuration
+        let min_interval_seconds = rule_context
+            .get(RuleType::STREAM_005)
+            .and_then(|rule_type| rule_type.config_detail.get_threshold_int())
+            .unwrap_or(300);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if let AWSResourceType::KinesisFirehoseDeliveryStream = &resource.type_ {
+                    if let Some(properties) = &resource.properties {
+                        for destination in [
+                            "ExtendedS3DestinationConfiguration",
+                            "S3DestinationConfiguration",
+                        ] {
+                            if let Some(buffering_hints) = properties
+                                .get(destination)
+                                .and_then(|config| config.get("BufferingHints"))
+                            {
+                                let small_interval = buffering_hints
+                                    .get("IntervalInSeconds")
+                                    .and_then(|v| v.as_u64())
+                                    .is_some_and(|v| v < min_interval_seconds);
+                                let small_size = buffering_hints
+                                    .get("SizeInMBs")
+                                    .and_then(|v| v.as_u64())
+                                    .is_some_and(|v| v < FIREHOSE_DEFAULT_BUFFER_SIZE_MB);
+                                if small_interval || small_size {
+                                    error_reporter.add_error(
+                                        Box::new(StreamViolation::FirehoseSmallBuffer),
+                                        key,
+                                        line_marker
+                                            .get_resource_span(vec![
+                                                key,
+                                                "Properties",
+                                                destination,
+                                                "BufferingHints",
+                                            ])
+                                            .copied(),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::error_reporter::ErrorReporter;
+use crate::parsers::cfn::{CloudFormation, Resource};
+use crate::parsers::config::{RuleContext, RuleType, TagMatch, TagPolicy};
+use crate::parsers::iac::{AWSResourceType, InfratructureTemplate};
+use crate::parsers::LineMarker;
+use crate::rules::violations::TaggingViolation;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+
+/// Tag values by key. Values that aren't literals, such as `!Ref`, are `None`.
+pub type Tags = IndexMap<String, Option<String>>;
+
+/// Returns the property holding the resource's tags, or `None` if it can't be tagged.
+pub fn tags_property(resource: &Resource) -> Option<&'static str> {
+    resource.type_.tags_property().or_else(|| {
+        // Unknown resource types are checked when they declare tags
+        resource
+            .properties
+            .as_ref()
+            .is_some_and(|props| props.contains_key("Tags"))
+            .then_some("Tags")
+    })
+}
+
+/// Returns the tags of a resource, stack tags from the samconfig included. Tags on the
+/// resource take precedence over stack tags.
+pub fn resource_tags(cloudformation: &CloudFormation, resource: &Resource) -> Tags {
+    let mut tags: Tags = cloudformation
+        .stack_tags
+        .iter()
+        .map(|(k, v)| (k.clone(), Some(v.clone())))
+        .collect();
+    if let Some(resource_tags) = tags_property(resource).and_then(|property| {
+        resource
+            .properties
+            .as_ref()
+            .and_then(|props| props.get(property))
+    }) {
+        tags.extend(parse_tags(resource_tags));
+    }
+    tags
+}
+
+/// Parses CloudFormation `[{Key, Value}]` tags, SAM map tags and `[{key: value}]` lists.
+pub fn parse_tags(tags: &Value) -> Tags {
+    let entries = |mapping: &serde_yaml::Mapping| -> Tags {
+        mapping
+            .iter()
+            .filter_map(|(k, v)| Some((k.as_str()?.to_string(), tag_value(v))))
+            .collect()
+    };
+    match tags {
+        Value::Mapping(mapping) => entries(mapping),
+        Value::Sequence(seq) => seq
+            .iter()
+            .filter_map(|tag| tag.as_mapping())
+            .flat_map(|tag| match tag.get("Key").and_then(|key| key.as_str()) {
+                Some(key) => Tags::from([(key.to_string(), tag.get("Value").and_then(tag_value))]),
+                None => entries(tag),
+            })
+            .collect(),
+        _ => Tags::new(),
+    }
+}
+
+fn tag_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Checks tags against a policy, returning a note on what is missing or invalid.
+pub fn tag_policy_findings(policy: &TagPolicy, tags: &Tags) -> Option<String> {
+    let mut missing = vec![];
+    let mut invalid = vec![];
+    for requirement in &policy.tags {
+        match tags.get(&requirement.key) {
+            None => missing.push(requirement.key.as_str()),
+            // Values only known at deploy time are assumed to be valid
+            Some(None) => {}
+            Some(Some(value)) => {
+                if let Some(pattern) = &requirement.pattern {
+                    if !pattern.is_match(value) {
+                        invalid.push(format!(
+                            "tag {} value \"{}\" does not match {}",
+                            requirement.key,
+                            value,
+                            pattern.as_str()
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut findings = vec![];
+    match policy.match_ {
+        TagMatch::All => {
+            if !missing.is_empty() {
+                findings.push(format!("missing tags {}", missing.join(", ")));
+            }
+        }
+        // An empty list requires the resource to have at least one tag
+        TagMatch::Any if policy.tags.is_empty() => {
+            if tags.is_empty() {
+                findings.push("the resource has no tags".to_string());
+            }
+        }
+        TagMatch::Any => {
+            if missing.len() + invalid.len() < policy.tags.len() {
+                return None;
+            }
+            let keys: Vec<&str> = policy.tags.iter().map(|tag| tag.key.as_str()).collect();
+            findings.push(format!("requires one of the tags {}", keys.join(", ")));
+        }
+    }
+    findings.extend(invalid);
+    (!findings.is_empty()).then(|| findings.join("; "))
+}
+
+pub fn check_tagging_policy<L: LineMarker>(
+    infra_template: &InfratructureTemplate,
+    rule_context: &RuleContext,
+    error_reporter: &mut ErrorReporter,
+    line_marker: &L,
+) {
+    if let Some(cloudformation) = &infra_template.cloudformation {
+        let Some(policy) = rule_context
+            .get(RuleType::TAG_001)
+            .and_then(|rule_type| rule_type.config_detail.get_tag_policy())
+        else {
+            return;
+        };
+
+        // LAMBDA-003 reports the tags of Lambda functions, so they are only reported once
+        let skip_functions = rule_context.enabled(RuleType::LAMBDA_003);
+
+        if let Some(resources) = &cloudformation.resources {
+            for (key, resource) in resources {
+                if skip_functions
+                    && matches!(
+                        resource.type_,
+                        AWSResourceType::LambdaFunction | AWSResourceType::LambdaServerlessFunction
+                    )
+                {
+                    continue;
+                }
+                let Some(property) = tags_property(resource) else {
+                    continue;
+                };
+                let tags = resource_tags(cloudformation, resource);
+                if let Some(note) = tag_policy_findings(policy, &tags) {
+                    error_reporter.add_error_with_note(
+                        Box::new(TaggingViolation::TagPolicy),
+                        key,
+                        line_marker
+                            .get_resource_span(vec![key, "Properties", property])
+                            .or_else(|| line_marker.get_resource_span(vec![key]))
+                            .copied(),
+                        note,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -15,6 +15,14 @@ pub enum LambdaViolation {
     PowertoolsLogLevel,
     PowertoolsLoggerLogEvent,
     PowertoolsLoggerSampleRate,
+    MemorySize,
+    ApiTimeout,
+    EphemeralStorage,
+    ProvisionedConcurrency,
+    SmallBatchSize,
+    MissingFilterCriteria,
+    UnboundedStreamRetries,
+    SqsRetryAmplification,
 }
 
 impl Violation for LambdaViolation {
@@ -50,6 +58,39 @@ impl Violation for LambdaViolation {
             LambdaViolation::PowertoolsLoggerSampleRate => {
                 "Set the POWERTOOLS_LOGGER_SAMPLE_RATE environment variable to a value between 0 and 1 \
                 to sample logs and reduce logging costs when using AWS Lambda Powertools.".to_string()
+            },
+            LambdaViolation::MemorySize => {
+                "The Lambda function memory size is above the configured ceiling. \
+                Lambda is billed per GB-second, consider right-sizing the memory with AWS Lambda Power Tuning.".to_string()
+            },
+            LambdaViolation::ApiTimeout => {
+                "The API-triggered Lambda function timeout is close to the 900 second maximum. \
+                API Gateway stops waiting after 29 seconds, so long timeouts only bill for work nobody receives.".to_string()
+            },
+            LambdaViolation::EphemeralStorage => {
+                "The Lambda function ephemeral storage is larger than the configured size. \
+                Storage above the free 512 MB is billed per GB-second, consider reducing it.".to_string()
+            },
+            LambdaViolation::ProvisionedConcurrency => {
+                "Provisioned concurrency is configured for the Lambda function. \
+                Provisioned concurrency is billed whether or not it is used, consider disabling it in non-production environments.".to_string()
+            },
+            LambdaViolation::SmallBatchSize => {
+                "The event source mapping uses a small batch size without a batching window. \
+                Each batch is a separate invocation, consider increasing BatchSize or setting MaximumBatchingWindowInSeconds.".to_string()
+            },
+            LambdaViolation::MissingFilterCriteria => {
+                "The stream event source mapping has no FilterCriteria. \
+                Every record invokes the function, consider filtering out records the function ignores before they are billed.".to_string()
+            },
+            LambdaViolation::UnboundedStreamRetries => {
+                "The stream event source mapping retries failed batches until the records expire. \
+                Set MaximumRetryAttempts or MaximumRecordAgeInSeconds and enable BisectBatchOnFunctionError \
+                to stop a single bad record from being reprocessed indefinitely.".to_string()
+            },
+            LambdaViolation::SqsRetryAmplification => {
+                "The SQS queue maxReceiveCount of the event source exceeds the configured threshold. \
+                A single failing message may be processed many times before it moves to the dead-letter queue, consider lowering maxReceiveCount.".to_string()
             }
         }
     }
@@ -63,6 +104,14 @@ impl Violation for LambdaViolation {
             LambdaViolation::PowertoolsLogLevel => "LAMBDA-005".to_string(),
             LambdaViolation::PowertoolsLoggerLogEvent => "LAMBDA-006".to_string(),
             LambdaViolation::PowertoolsLoggerSampleRate => "LAMBDA-007".to_string(),
+            LambdaViolation::MemorySize => "LAMBDA-008".to_string(),
+            LambdaViolation::ApiTimeout => "LAMBDA-009".to_string(),
+            LambdaViolation::EphemeralStorage => "LAMBDA-010".to_string(),
+            LambdaViolation::ProvisionedConcurrency => "LAMBDA-011".to_string(),
+            LambdaViolation::SmallBatchSize => "LAMBDA-012".to_string(),
+            LambdaViolation::MissingFilterCriteria => "LAMBDA-013".to_string(),
+            LambdaViolation::UnboundedStreamRetries => "LAMBDA-014".to_string(),
+            LambdaViolation::SqsRetryAmplification => "LAMBDA-015".to_string(),
         }
     }
 }
@@ -72,6 +121,12 @@ pub enum CloudWatchViolation {
     LogRetentionTooLong,
     NoLogRetention,
     InfrequentAccessLogGroupClass,
+    InfrequentAccessIncompatibleConsumers,
+    HighResolutionAlarm,
+    TooManyAlarms,
+    MetricFilterOnInfrequentAccess,
+    UnfilteredSubscription,
+    DashboardFreeTier,
 }
 
 impl Violation for CloudWatchViolation {
@@ -86,6 +141,30 @@ impl Violation for CloudWatchViolation {
             CloudWatchViolation::InfrequentAccessLogGroupClass => {
                 "The log group is using STANDARD class. Consider using INFREQUENT_ACCESS to save costs.".to_string()
             }
+            CloudWatchViolation::InfrequentAccessIncompatibleConsumers => {
+                "The log group is using STANDARD class, but it has metric filters or subscription filters \
+                that INFREQUENT_ACCESS does not support. Remove them before switching to INFREQUENT_ACCESS.".to_string()
+            }
+            CloudWatchViolation::HighResolutionAlarm => {
+                "The alarm uses a high-resolution period below 60 seconds. \
+                High-resolution alarms cost three times as much, consider a period of 60 seconds or more outside production.".to_string()
+            }
+            CloudWatchViolation::TooManyAlarms => {
+                "The Lambda function has more alarms than the configured threshold. \
+                Each alarm is billed per metric, consider consolidating them with metric math or composite alarms.".to_string()
+            }
+            CloudWatchViolation::MetricFilterOnInfrequentAccess => {
+                "The metric filter targets a log group using the INFREQUENT_ACCESS class, which does not support metric filters. \
+                Use the STANDARD class for this log group or remove the metric filter.".to_string()
+            }
+            CloudWatchViolation::UnfilteredSubscription => {
+                "The subscription filter streams every log event to its destination. \
+                Set a FilterPattern so that only the events you need are delivered and billed.".to_string()
+            }
+            CloudWatchViolation::DashboardFreeTier => {
+                "The template defines more dashboards than the configured threshold. \
+                Only the first 3 dashboards are free, consider consolidating them.".to_string()
+            }
         }
     }
 
@@ -93,7 +172,791 @@ impl Violation for CloudWatchViolation {
         match self {
             CloudWatchViolation::LogRetentionTooLong => "CW-001".to_string(),
             CloudWatchViolation::NoLogRetention => "CW-002".to_string(),
-            CloudWatchViolation::InfrequentAccessLogGroupClass => "CW-003".to_string(),
+            CloudWatchViolation::InfrequentAccessLogGroupClass
+            | CloudWatchViolation::InfrequentAccessIncompatibleConsumers => "CW-003".to_string(),
+            CloudWatchViolation::HighResolutionAlarm => "CW-004".to_string(),
+            CloudWatchViolation::TooManyAlarms => "CW-005".to_string(),
+            CloudWatchViolation::MetricFilterOnInfrequentAccess => "CW-006".to_string(),
+            CloudWatchViolation::UnfilteredSubscription => "CW-007".to_string(),
+            CloudWatchViolation::DashboardFreeTier => "CW-008".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum EcsViolation {
+    ARMArchitecture,
+    FargateSpot,
+    OversizedTask,
+    ContainerInsights,
+    AwslogsNoLogRetention,
+}
+
+impl Violation for EcsViolation {
+    fn message(&self) -> String {
+        match self {
+            EcsViolation::ARMArchitecture => {
+                "Consider using ARM64 as the task CPU architecture. \
+                Fargate tasks on Graviton can be up to 20% cheaper than equivalent x86 tasks.".to_string()
+            }
+            EcsViolation::FargateSpot => {
+                "The service does not use the FARGATE_SPOT capacity provider. \
+                Consider running non-production services on Fargate Spot to save up to 70% of compute costs.".to_string()
+            }
+            EcsViolation::OversizedTask => {
+                "The task CPU or memory is larger than the configured size. \
+                Consider right-sizing the task to avoid paying for idle capacity.".to_string()
+            }
+            EcsViolation::ContainerInsights => {
+                "Container Insights is enabled on the cluster. \
+                Consider disabling it where detailed container metrics are not needed to reduce CloudWatch costs.".to_string()
+            }
+            EcsViolation::AwslogsNoLogRetention => {
+                "The container uses the awslogs log driver without a log group that has a retention policy. \
+                Please explicitly create a log group with a retention policy.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            EcsViolation::ARMArchitecture => "ECS-001".to_string(),
+            EcsViolation::FargateSpot => "ECS-002".to_string(),
+            EcsViolation::OversizedTask => "ECS-003".to_string(),
+            EcsViolation::ContainerInsights => "ECS-004".to_string(),
+            EcsViolation::AwslogsNoLogRetention => "ECS-005".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum ApiGatewayViolation {
+    HttpApiCandidate,
+    CacheClusterSize,
+    ExecutionLogging,
+    AccessLogNoRetention,
+}
+
+impl Violation for ApiGatewayViolation {
+    fn message(&self) -> String {
+        match self {
+            ApiGatewayViolation::HttpApiCandidate => {
+                "The REST API does not use any REST-only features. \
+                Consider using an HTTP API, which is up to 70% cheaper than a REST API.".to_string()
+            }
+            ApiGatewayViolation::CacheClusterSize => {
+                "The API cache cluster is larger than the configured size. \
+                Cache clusters are billed hourly, consider using a smaller cache outside production.".to_string()
+            }
+            ApiGatewayViolation::ExecutionLogging => {
+                "Execution logging is set to INFO with DataTraceEnabled, which logs full requests and responses. \
+                Consider using ERROR logging and disabling data tracing to reduce logging costs.".to_string()
+            }
+            ApiGatewayViolation::AccessLogNoRetention => {
+                "The access log destination is a log group without a retention policy. \
+                Consider setting a retention policy to save costs and improve log management efficiency.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            ApiGatewayViolation::HttpApiCandidate => "APIGW-001".to_string(),
+            ApiGatewayViolation::CacheClusterSize => "APIGW-002".to_string(),
+            ApiGatewayViolation::ExecutionLogging => "APIGW-003".to_string(),
+            ApiGatewayViolation::AccessLogNoRetention => "APIGW-004".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum StepFunctionsViolation {
+    ExpressWorkflow,
+    LoggingExecutionData,
+    Tracing,
+}
+
+impl Violation for StepFunctionsViolation {
+    fn message(&self) -> String {
+        match self {
+            StepFunctionsViolation::ExpressWorkflow => {
+                "The STANDARD workflow is invoked from a high-volume event source. \
+                Consider using an EXPRESS workflow, which is billed per request and duration instead of per state transition.".to_string()
+            }
+            StepFunctionsViolation::LoggingExecutionData => {
+                "The state machine logs ALL events including execution data. \
+                Consider logging ERROR or FATAL events only, or excluding execution data, to reduce logging costs.".to_string()
+            }
+            StepFunctionsViolation::Tracing => {
+                "X-Ray tracing is enabled on the state machine. \
+                Consider disabling tracing in non-production environments to reduce tracing costs.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            StepFunctionsViolation::ExpressWorkflow => "SFN-001".to_string(),
+            StepFunctionsViolation::LoggingExecutionData => "SFN-002".to_string(),
+            StepFunctionsViolation::Tracing => "SFN-003".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum StreamViolation {
+    ProvisionedShardCount,
+    OnDemandStream,
+    ExtendedRetention,
+    EnhancedMonitoring,
+    FirehoseSmallBuffer,
+}
+
+impl Violation for StreamViolation {
+    fn message(&self) -> String {
+        match self {
+            StreamViolation::ProvisionedShardCount => {
+                "The provisioned stream has more shards than the configured threshold. \
+                Consider ON_DEMAND capacity mode for spiky workloads instead of paying for idle shards.".to_string()
+            }
+            StreamViolation::OnDemandStream => {
+                "The stream uses ON_DEMAND capacity mode. \
+                On-demand streams are billed per stream hour, a PROVISIONED stream with one shard is cheaper for low traffic.".to_string()
+            }
+            StreamViolation::ExtendedRetention => {
+                "The stream retention period is longer than the configured threshold. \
+                Extended retention is billed per shard hour, consider reducing it to save costs.".to_string()
+            }
+            StreamViolation::EnhancedMonitoring => {
+                "Enhanced monitoring is enabled on the MSK cluster. \
+                Per-broker, per-topic and per-partition metrics are billed as CloudWatch custom metrics, consider using DEFAULT monitoring.".to_string()
+            }
+            StreamViolation::FirehoseSmallBuffer => {
+                "The Firehose buffering hints flush small batches, which produces many tiny S3 objects. \
+                Consider increasing the buffer size and interval to reduce S3 request and processing costs.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            StreamViolation::ProvisionedShardCount => "STREAM-001".to_string(),
+            StreamViolation::OnDemandStream => "STREAM-002".to_string(),
+            StreamViolation::ExtendedRetention => "STREAM-003".to_string(),
+            StreamViolation::EnhancedMonitoring => "STREAM-004".to_string(),
+            StreamViolation::FirehoseSmallBuffer => "STREAM-005".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum MessagingViolation {
+    ShortPolling,
+    MessageRetentionPeriod,
+    FifoQueue,
+    ArchiveRetention,
+    SnsFilterPolicy,
+}
+
+impl Violation for MessagingViolation {
+    fn message(&self) -> String {
+        match self {
+            MessagingViolation::ShortPolling => {
+                "The queue uses short polling. \
+                Consider setting ReceiveMessageWaitTimeSeconds up to 20 seconds to reduce the number of empty receives you pay for.".to_string()
+            }
+            MessagingViolation::MessageRetentionPeriod => {
+                "The queue message retention period is longer than the configured threshold. \
+                Consider reducing it outside production to avoid paying for stale messages.".to_string()
+            }
+            MessagingViolation::FifoQueue => {
+                "The queue is a FIFO queue but is not configured as requiring ordering. \
+                Standard queues are cheaper per request, consider using one when ordering is not needed.".to_string()
+            }
+            MessagingViolation::ArchiveRetention => {
+                "The event archive has no retention period, so events are stored indefinitely. \
+                Consider setting RetentionDays to save storage costs.".to_string()
+            }
+            MessagingViolation::SnsFilterPolicy => {
+                "The SNS subscription delivers every message to a Lambda function without a filter policy. \
+                Consider adding a FilterPolicy so the function is only invoked for messages it needs.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            MessagingViolation::ShortPolling => "MSG-001".to_string(),
+            MessagingViolation::MessageRetentionPeriod => "MSG-002".to_string(),
+            MessagingViolation::FifoQueue => "MSG-003".to_string(),
+            MessagingViolation::ArchiveRetention => "MSG-004".to_string(),
+            MessagingViolation::SnsFilterPolicy => "MSG-005".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum ElastiCacheViolation {
+    PreviousGeneration,
+    NonGraviton,
+    TooManyReplicas,
+}
+
+impl Violation for ElastiCacheViolation {
+    fn message(&self) -> String {
+        match self {
+            ElastiCacheViolation::PreviousGeneration => {
+                "The cache uses a previous generation node type. \
+                Current generation nodes offer better price performance for the same workload."
+                    .to_string()
+            }
+            ElastiCacheViolation::NonGraviton => "The cache does not use a Graviton node type. \
+                Graviton nodes offer better price performance than equivalent x86 nodes."
+                .to_string(),
+            ElastiCacheViolation::TooManyReplicas => {
+                "The replication group has more replicas than the configured threshold. \
+                Each replica is billed as a full node, consider fewer replicas outside production."
+                    .to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            ElastiCacheViolation::PreviousGeneration => "ELASTICACHE-001".to_string(),
+            ElastiCacheViolation::NonGraviton => "ELASTICACHE-002".to_string(),
+            ElastiCacheViolation::TooManyReplicas => "ELASTICACHE-003".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum OpenSearchViolation {
+    PreviousGeneration,
+    NonGraviton,
+    DedicatedMasterOnSmallDomain,
+    Gp2Volume,
+    NoWarmStorage,
+}
+
+impl Violation for OpenSearchViolation {
+    fn message(&self) -> String {
+        match self {
+            OpenSearchViolation::PreviousGeneration => {
+                "The domain uses a previous generation instance type. \
+                Current generation instances offer better price performance for the same workload.".to_string()
+            }
+            OpenSearchViolation::NonGraviton => {
+                "The domain does not use a Graviton instance type. \
+                Graviton instances offer better price performance than equivalent x86 instances.".to_string()
+            }
+            OpenSearchViolation::DedicatedMasterOnSmallDomain => {
+                "The domain uses dedicated master nodes with fewer data nodes than the configured threshold. \
+                Dedicated masters can cost more than the data nodes of a small domain, consider disabling them.".to_string()
+            }
+            OpenSearchViolation::Gp2Volume => {
+                "The domain uses gp2 EBS volumes. \
+                gp3 volumes are about 10% cheaper and include baseline IOPS and throughput.".to_string()
+            }
+            OpenSearchViolation::NoWarmStorage => {
+                "The domain keeps a large amount of data on hot storage without UltraWarm or cold storage. \
+                Move older indices to UltraWarm or cold storage to reduce the cost of long retention.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            OpenSearchViolation::PreviousGeneration => "OPENSEARCH-001".to_string(),
+            OpenSearchViolation::NonGraviton => "OPENSEARCH-002".to_string(),
+            OpenSearchViolation::DedicatedMasterOnSmallDomain => "OPENSEARCH-003".to_string(),
+            OpenSearchViolation::Gp2Volume => "OPENSEARCH-004".to_string(),
+            OpenSearchViolation::NoWarmStorage => "OPENSEARCH-005".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum CloudFrontViolation {
+    PriceClass,
+    Compression,
+    CachingDisabled,
+    RealtimeLogs,
+    LogBucketLifecycle,
+}
+
+impl Violation for CloudFrontViolation {
+    fn message(&self) -> String {
+        match self {
+            CloudFrontViolation::PriceClass => {
+                "The distribution price class is not one of the allowed price classes. \
+                If your traffic is regional, a lower price class avoids paying for the most expensive edge locations.".to_string()
+            }
+            CloudFrontViolation::Compression => {
+                "The cache behavior does not compress objects. \
+                Set Compress to true to reduce the data transfer out you pay for.".to_string()
+            }
+            CloudFrontViolation::CachingDisabled => {
+                "The cache behavior for static content uses the CachingDisabled cache policy. \
+                Every request goes to the origin, consider caching static content at the edge.".to_string()
+            }
+            CloudFrontViolation::RealtimeLogs => {
+                "The cache behavior sends real-time logs. \
+                Real-time logs are billed per log line, consider standard logging outside production.".to_string()
+            }
+            CloudFrontViolation::LogBucketLifecycle => {
+                "The distribution writes standard logs to a bucket without a lifecycle configuration. \
+                Consider expiring or transitioning old access logs to save storage costs.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            CloudFrontViolation::PriceClass => "CLOUDFRONT-001".to_string(),
+            CloudFrontViolation::Compression => "CLOUDFRONT-002".to_string(),
+            CloudFrontViolation::CachingDisabled => "CLOUDFRONT-003".to_string(),
+            CloudFrontViolation::RealtimeLogs => "CLOUDFRONT-004".to_string(),
+            CloudFrontViolation::LogBucketLifecycle => "CLOUDFRONT-005".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum EcrViolation {
+    MissingLifecyclePolicy,
+    UntaggedImageExpiry,
+}
+
+impl Violation for EcrViolation {
+    fn message(&self) -> String {
+        match self {
+            EcrViolation::MissingLifecyclePolicy => {
+                "The repository has no lifecycle policy, so images accumulate indefinitely. \
+                Add a LifecyclePolicy to expire untagged and old images and reduce storage costs."
+                    .to_string()
+            }
+            EcrViolation::UntaggedImageExpiry => {
+                "The lifecycle policy keeps untagged images longer than the configured threshold. \
+                Expire untagged images sooner to reduce storage costs."
+                    .to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            EcrViolation::MissingLifecyclePolicy => "ECR-001".to_string(),
+            EcrViolation::UntaggedImageExpiry => "ECR-002".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum FileSystemViolation {
+    EfsLifecyclePolicy,
+    EfsProvisionedThroughput,
+    EfsOneZone,
+    FsxBackupRetention,
+}
+
+impl Violation for FileSystemViolation {
+    fn message(&self) -> String {
+        match self {
+            FileSystemViolation::EfsLifecyclePolicy => {
+                "The EFS file system has no lifecycle policy transitioning files to Infrequent Access or Archive. \
+                Add LifecyclePolicies with TransitionToIA or TransitionToArchive to reduce storage costs.".to_string()
+            }
+            FileSystemViolation::EfsProvisionedThroughput => {
+                "The EFS file system uses provisioned throughput, which is billed whether or not it is used. \
+                Consider bursting or elastic throughput unless the workload needs a sustained rate.".to_string()
+            }
+            FileSystemViolation::EfsOneZone => {
+                "The EFS file system uses Regional storage. \
+                One Zone storage costs about half as much, consider it in non-production environments.".to_string()
+            }
+            FileSystemViolation::FsxBackupRetention => {
+                "The FSx file system keeps automatic backups longer than the configured threshold. \
+                Reduce AutomaticBackupRetentionDays to save backup storage costs.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            FileSystemViolation::EfsLifecyclePolicy => "FS-001".to_string(),
+            FileSystemViolation::EfsProvisionedThroughput => "FS-002".to_string(),
+            FileSystemViolation::EfsOneZone => "FS-003".to_string(),
+            FileSystemViolation::FsxBackupRetention => "FS-004".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum SecretsViolation {
+    TooManySecrets,
+    TooManyKmsKeys,
+    NonRotatingSecret,
+    SingleUseKmsKey,
+    AdvancedParameter,
+}
+
+impl Violation for SecretsViolation {
+    fn message(&self) -> String {
+        match self {
+            SecretsViolation::TooManySecrets => {
+                "The template defines more secrets than the configured threshold. \
+                Each secret has a fixed monthly fee, consider sharing secrets between functions.".to_string()
+            }
+            SecretsViolation::TooManyKmsKeys => {
+                "The template defines more customer managed KMS keys than the configured threshold. \
+                Each key has a fixed monthly fee, consider sharing keys between resources.".to_string()
+            }
+            SecretsViolation::NonRotatingSecret => {
+                "The secret holds a static value that is never rotated. \
+                Consider a free SSM String parameter for configuration that is not sensitive.".to_string()
+            }
+            SecretsViolation::SingleUseKmsKey => {
+                "The customer managed KMS key is used by a single resource. \
+                Consider the AWS managed key of the service, which has no monthly fee.".to_string()
+            }
+            SecretsViolation::AdvancedParameter => {
+                "The SSM parameter uses the Advanced tier for a value under 4 KB. \
+                Standard parameters are free, consider the Standard tier.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            SecretsViolation::TooManySecrets => "SECRET-001".to_string(),
+            SecretsViolation::TooManyKmsKeys => "SECRET-002".to_string(),
+            SecretsViolation::NonRotatingSecret => "SECRET-003".to_string(),
+            SecretsViolation::SingleUseKmsKey => "SECRET-004".to_string(),
+            SecretsViolation::AdvancedParameter => "SECRET-005".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum GlueViolation {
+    LargeWorkerType,
+    TooManyWorkers,
+    StandardExecutionClass,
+    FrequentCrawler,
+}
+
+impl Violation for GlueViolation {
+    fn message(&self) -> String {
+        match self {
+            GlueViolation::LargeWorkerType => {
+                "The Glue job uses a G.2X or larger worker type. \
+                Larger workers cost more DPUs per hour, consider G.1X outside production.".to_string()
+            }
+            GlueViolation::TooManyWorkers => {
+                "The Glue job uses more workers than the configured threshold. \
+                Each worker is billed per DPU hour, consider fewer workers outside production.".to_string()
+            }
+            GlueViolation::StandardExecutionClass => {
+                "The Glue job does not use the FLEX execution class. \
+                Flexible execution is about a third cheaper for jobs that are not time sensitive.".to_string()
+            }
+            GlueViolation::FrequentCrawler => {
+                "The Glue crawler runs more often than the configured threshold. \
+                Each run is billed per DPU hour, consider a less frequent schedule or S3 event notifications.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            GlueViolation::LargeWorkerType => "GLUE-001".to_string(),
+            GlueViolation::TooManyWorkers => "GLUE-002".to_string(),
+            GlueViolation::StandardExecutionClass => "GLUE-003".to_string(),
+            GlueViolation::FrequentCrawler => "GLUE-004".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum AthenaViolation {
+    MissingBytesScannedCutoff,
+    ConfigurationNotEnforced,
+}
+
+impl Violation for AthenaViolation {
+    fn message(&self) -> String {
+        match self {
+            AthenaViolation::MissingBytesScannedCutoff => {
+                "The Athena workgroup has no BytesScannedCutoffPerQuery. \
+                Queries are billed per TB scanned, set a data usage limit to cap the cost of a runaway query.".to_string()
+            }
+            AthenaViolation::ConfigurationNotEnforced => {
+                "The Athena workgroup does not enforce its configuration. \
+                Set EnforceWorkGroupConfiguration to true so clients cannot bypass the workgroup limits.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            AthenaViolation::MissingBytesScannedCutoff => "ATHENA-001".to_string(),
+            AthenaViolation::ConfigurationNotEnforced => "ATHENA-002".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum SageMakerViolation {
+    RealTimeEndpoint,
+    GpuNotebookWithoutAutoStop,
+    UnscaledEndpoint,
+    DomainIdleShutdown,
+}
+
+impl Violation for SageMakerViolation {
+    fn message(&self) -> String {
+        match self {
+            SageMakerViolation::RealTimeEndpoint => {
+                "The endpoint configuration uses real-time instances on CPU. \
+                Serverless inference is billed per request, consider ServerlessConfig outside production.".to_string()
+            }
+            SageMakerViolation::GpuNotebookWithoutAutoStop => {
+                "The notebook instance uses a GPU instance type without a lifecycle configuration. \
+                Add a lifecycle configuration that stops the notebook when it is idle.".to_string()
+            }
+            SageMakerViolation::UnscaledEndpoint => {
+                "The endpoint configuration starts more instances than the configured threshold without autoscaling. \
+                Register a scalable target so idle capacity is scaled in.".to_string()
+            }
+            SageMakerViolation::DomainIdleShutdown => {
+                "The SageMaker domain does not shut down idle JupyterLab or Code Editor applications. \
+                Enable idle shutdown in the default user settings to stop paying for idle instances.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            SageMakerViolation::RealTimeEndpoint => "SAGEMAKER-001".to_string(),
+            SageMakerViolation::GpuNotebookWithoutAutoStop => "SAGEMAKER-002".to_string(),
+            SageMakerViolation::UnscaledEndpoint => "SAGEMAKER-003".to_string(),
+            SageMakerViolation::DomainIdleShutdown => "SAGEMAKER-004".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum ScheduleViolation {
+    FrequentSchedule,
+}
+
+impl Violation for ScheduleViolation {
+    fn message(&self) -> String {
+        match self {
+            ScheduleViolation::FrequentSchedule => {
+                "The schedule runs more often than the configured threshold. \
+                Every run invokes its targets, consider a less frequent schedule or an event-driven trigger.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            ScheduleViolation::FrequentSchedule => "SCHEDULE-001".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum BackupViolation {
+    RetentionPeriod,
+    MissingColdStorage,
+    FrequentBackups,
+    SnapshotOnDeletion,
+}
+
+impl Violation for BackupViolation {
+    fn message(&self) -> String {
+        match self {
+            BackupViolation::RetentionPeriod => {
+                "Backups are retained longer than the configured threshold. \
+                Consider reducing DeleteAfterDays to save backup storage costs.".to_string()
+            }
+            BackupViolation::MissingColdStorage => {
+                "Backups are retained for a long period without moving to cold storage. \
+                Set MoveToColdStorageAfterDays, cold storage costs about a fifth of warm storage.".to_string()
+            }
+            BackupViolation::FrequentBackups => {
+                "The backup rule runs more often than the configured threshold. \
+                Each backup adds storage, consider a less frequent schedule outside production.".to_string()
+            }
+            BackupViolation::SnapshotOnDeletion => {
+                "The resource keeps a snapshot of a large volume when it is deleted. \
+                Snapshots of deleted non-production resources are rarely restored, consider DeletionPolicy Delete.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            BackupViolation::RetentionPeriod => "BACKUP-001".to_string(),
+            BackupViolation::MissingColdStorage => "BACKUP-002".to_string(),
+            BackupViolation::FrequentBackups => "BACKUP-003".to_string(),
+            BackupViolation::SnapshotOnDeletion => "BACKUP-004".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum CodeBuildViolation {
+    LargeComputeType,
+    X86Image,
+    MissingCache,
+    NoLogRetention,
+}
+
+impl Violation for CodeBuildViolation {
+    fn message(&self) -> String {
+        match self {
+            CodeBuildViolation::LargeComputeType => {
+                "The CodeBuild project uses a large compute type. \
+                Build minutes are billed by compute type, consider BUILD_GENERAL1_SMALL or MEDIUM for simple builds.".to_string()
+            }
+            CodeBuildViolation::X86Image => {
+                "The CodeBuild project uses an x86 managed image. \
+                ARM_CONTAINER builds with the aarch64 image cost less per build minute.".to_string()
+            }
+            CodeBuildViolation::MissingCache => {
+                "The CodeBuild project has no build cache. \
+                Caching dependencies with a LOCAL or S3 cache shortens builds and reduces build minutes.".to_string()
+            }
+            CodeBuildViolation::NoLogRetention => {
+                "The CodeBuild project writes build logs to a log group without retention. \
+                Logs are kept forever by default, consider a log group with RetentionInDays.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            CodeBuildViolation::LargeComputeType => "CODEBUILD-001".to_string(),
+            CodeBuildViolation::X86Image => "CODEBUILD-002".to_string(),
+            CodeBuildViolation::MissingCache => "CODEBUILD-003".to_string(),
+            CodeBuildViolation::NoLogRetention => "CODEBUILD-004".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum CodePipelineViolation {
+    FrequentV2Pipeline,
+    InfrequentV1Pipeline,
+}
+
+impl Violation for CodePipelineViolation {
+    fn message(&self) -> String {
+        match self {
+            CodePipelineViolation::FrequentV2Pipeline => {
+                "The V2 pipeline runs more often than the configured threshold. \
+                V2 pipelines are billed per action minute, the flat V1 monthly fee may be cheaper.".to_string()
+            }
+            CodePipelineViolation::InfrequentV1Pipeline => {
+                "The V1 pipeline runs less often than the configured threshold. \
+                V1 pipelines have a flat monthly fee, V2 pipelines billed per action minute may be cheaper.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            CodePipelineViolation::FrequentV2Pipeline => "CODEPIPELINE-001".to_string(),
+            CodePipelineViolation::InfrequentV1Pipeline => "CODEPIPELINE-002".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum RedshiftViolation {
+    TooManyNodes,
+    BaseCapacity,
+    IndefiniteSnapshotRetention,
+}
+
+impl Violation for RedshiftViolation {
+    fn message(&self) -> String {
+        match self {
+            RedshiftViolation::TooManyNodes => {
+                "The Redshift cluster has more nodes than the configured threshold. \
+                Each node is billed per hour, consider fewer nodes outside production.".to_string()
+            }
+            RedshiftViolation::BaseCapacity => {
+                "The Redshift Serverless workgroup base capacity is above the configured threshold. \
+                Queries are billed per RPU hour, consider a lower BaseCapacity.".to_string()
+            }
+            RedshiftViolation::IndefiniteSnapshotRetention => {
+                "Manual snapshots of the Redshift cluster are retained indefinitely. \
+                Set ManualSnapshotRetentionPeriod to a number of days to save snapshot storage costs.".to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            RedshiftViolation::TooManyNodes => "REDSHIFT-001".to_string(),
+            RedshiftViolation::BaseCapacity => "REDSHIFT-002".to_string(),
+            RedshiftViolation::IndefiniteSnapshotRetention => "REDSHIFT-003".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum DocDbViolation {
+    TooManyInstances,
+    NonGraviton,
+}
+
+impl Violation for DocDbViolation {
+    fn message(&self) -> String {
+        match self {
+            DocDbViolation::TooManyInstances => {
+                "The DocumentDB cluster has more instances than the configured threshold. \
+                Each instance is billed per hour, consider fewer replicas outside production."
+                    .to_string()
+            }
+            DocDbViolation::NonGraviton => {
+                "The DocumentDB instance does not use a Graviton instance class. \
+                Graviton instances offer better price performance than equivalent x86 instances."
+                    .to_string()
+            }
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            DocDbViolation::TooManyInstances => "DOCDB-001".to_string(),
+            DocDbViolation::NonGraviton => "DOCDB-002".to_string(),
+        }
+    }
+}
+
+#[derive(EnumIter, Debug, Display, PartialEq)]
+pub enum TaggingViolation {
+    TagPolicy,
+}
+
+impl Violation for TaggingViolation {
+    fn message(&self) -> String {
+        match self {
+            TaggingViolation::TagPolicy => "The resource does not meet the tagging policy. \
+                Tags are needed to allocate costs to teams and projects."
+                .to_string(),
+        }
+    }
+
+    fn code(&self) -> String {
+        match self {
+            TaggingViolation::TagPolicy => "TAG-001".to_string(),
         }
     }
 }
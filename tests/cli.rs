@@ -0,0 +1,78 @@
+use std::process::{Command, Output};
+
+fn ccs(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_ccs"))
+        .args(args)
+        .output()
+        .expect("Failed to run ccs")
+}
+
+#[test]
+fn test_explain_config() {
+    // No cloud provider or template is needed, and the `default` environment is explained
+    let output = ccs(&["--explain-config", "-c", "src/fixtures/cloudsaving.yaml"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("default:\n"));
+
+    let output = ccs(&[
+        "--explain-config",
+        "-c",
+        "src/fixtures/cloudsaving.yaml",
+        "-e",
+        "staging",
+    ]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let explained: serde_yaml::Value = serde_yaml::from_str(&stdout).unwrap();
+    let environments = explained.as_mapping().unwrap();
+    assert_eq!(environments.len(), 1);
+    assert_eq!(explained["staging"]["extends"], "prod");
+    assert_eq!(
+        explained["staging"]["LAMBDA_006"]["source"],
+        "environments.staging"
+    );
+}
+
+#[test]
+fn test_undefined_environment() {
+    let output = ccs(&[
+        "aws",
+        "-t",
+        "src/fixtures/aws/cfn-testing.yaml",
+        "-c",
+        "src/fixtures/cloudsaving.yaml",
+        "-e",
+        "prdo",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("environment `prdo` is not defined"));
+}
+
+#[test]
+fn test_config_without_cloudformation() {
+    // The default rules apply when the config has no `cloudformation` section
+    let output = ccs(&[
+        "--explain-config",
+        "-c",
+        "src/fixtures/cloudsaving-defaults.yaml",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let explained: serde_yaml::Value = serde_yaml::from_str(&stdout).unwrap();
+    assert_eq!(explained["default"]["LAMBDA_001"]["enabled"], true);
+    assert_eq!(explained["default"]["LAMBDA_001"]["source"], "default");
+
+    let output = ccs(&[
+        "--explain-config",
+        "-c",
+        "src/fixtures/cloudsaving-defaults.yaml",
+        "-e",
+        "typo",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("environment `typo` is not defined, expected one of: default"));
+}